@@ -0,0 +1,216 @@
+//! ASCII-armored, integrity-checked envelope for transpiled contract artifacts, so they survive
+//! text-only channels (logs, copy-paste, CI artifact stores) and carry a built-in corruption
+//! check independent of whether the payload happens to be valid JSON.
+//!
+//! Mirrors the shape of OpenPGP's ASCII armor: a header/footer pair bracketing base64-encoded
+//! payload wrapped to 64-character lines, followed by a `=`-prefixed base64 CRC-24 checksum line
+//! computed over the raw (pre-base64) payload.
+
+const HEADER: &str = "-----BEGIN AZTEC TRANSPILED CONTRACT-----";
+const FOOTER: &str = "-----END AZTEC TRANSPILED CONTRACT-----";
+const LINE_WIDTH: usize = 64;
+
+const CRC24_INIT: u32 = 0xB704CE;
+const CRC24_POLY: u32 = 0x1864CFB;
+
+/// Errors produced while armoring or dearmoring a payload.
+#[derive(Debug, thiserror::Error)]
+pub enum ArmorError {
+    #[error("malformed armor envelope: missing or mismatched header/footer")]
+    MalformedEnvelope,
+    #[error("invalid base64 encoding in armor payload")]
+    InvalidEncoding,
+    #[error("missing checksum line in armor envelope")]
+    MissingChecksum,
+    #[error("checksum mismatch: armor payload is corrupted")]
+    ChecksumMismatch,
+}
+
+/// Computes the standard CRC-24 checksum over `data`: generator `0x864CFB` (folded into
+/// `CRC24_POLY` with its implicit leading coefficient), initialized to `0xB704CE`, processing
+/// each byte MSB-first and masking the result to 24 bits.
+fn crc24(data: &[u8]) -> u32 {
+    let mut crc = CRC24_INIT;
+    for &byte in data {
+        crc ^= (byte as u32) << 16;
+        for _ in 0..8 {
+            crc <<= 1;
+            if crc & 0x0100_0000 != 0 {
+                crc ^= CRC24_POLY;
+            }
+        }
+    }
+    crc & 0x00FF_FFFF
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(BASE64_ALPHABET[(((b0 & 0x03) << 4) | (b1.unwrap_or(0) >> 4)) as usize] as char);
+
+        match b1 {
+            Some(b1) => out
+                .push(BASE64_ALPHABET[(((b1 & 0x0f) << 2) | (b2.unwrap_or(0) >> 6)) as usize] as char),
+            None => out.push('='),
+        }
+
+        match b2 {
+            Some(b2) => out.push(BASE64_ALPHABET[(b2 & 0x3f) as usize] as char),
+            None => out.push('='),
+        }
+    }
+    out
+}
+
+fn base64_decode_char(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+fn base64_decode(encoded: &str) -> Result<Vec<u8>, ArmorError> {
+    let chars: Vec<u8> = encoded.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    if chars.is_empty() || chars.len() % 4 != 0 {
+        return Err(ArmorError::InvalidEncoding);
+    }
+
+    let mut out = Vec::with_capacity(chars.len() / 4 * 3);
+    for group in chars.chunks(4) {
+        let pad = group.iter().filter(|&&b| b == b'=').count();
+        let mut sextets = [0u8; 4];
+        for (i, &b) in group.iter().enumerate() {
+            sextets[i] = if b == b'=' {
+                0
+            } else {
+                base64_decode_char(b).ok_or(ArmorError::InvalidEncoding)?
+            };
+        }
+
+        out.push((sextets[0] << 2) | (sextets[1] >> 4));
+        if pad < 2 {
+            out.push((sextets[1] << 4) | (sextets[2] >> 2));
+        }
+        if pad < 1 {
+            out.push((sextets[2] << 6) | sextets[3]);
+        }
+    }
+    Ok(out)
+}
+
+/// Wraps `payload` in an ASCII-armor envelope: header, base64 payload wrapped to 64-character
+/// lines, a `=`-prefixed base64 CRC-24 checksum line, and a footer.
+pub fn armor(payload: &[u8]) -> String {
+    let encoded = base64_encode(payload);
+    let mut out = String::new();
+    out.push_str(HEADER);
+    out.push_str("\n\n");
+    for line in encoded.as_bytes().chunks(LINE_WIDTH) {
+        out.push_str(std::str::from_utf8(line).expect("base64 output is ASCII"));
+        out.push('\n');
+    }
+    out.push('=');
+    out.push_str(&base64_encode(&crc24(payload).to_be_bytes()[1..]));
+    out.push('\n');
+    out.push_str(FOOTER);
+    out.push('\n');
+    out
+}
+
+/// Verifies and unwraps an envelope produced by [`armor`], returning the original payload.
+///
+/// Recomputes the CRC-24 over the decoded payload and compares it against the envelope's
+/// checksum line, surfacing [`ArmorError::ChecksumMismatch`] on a mismatch independent of whether
+/// the payload happens to be valid JSON.
+pub fn dearmor(armored: &str) -> Result<Vec<u8>, ArmorError> {
+    let mut lines = armored.lines();
+    let header_line = lines.next().ok_or(ArmorError::MalformedEnvelope)?;
+    if header_line.trim() != HEADER {
+        return Err(ArmorError::MalformedEnvelope);
+    }
+
+    let mut saw_footer = false;
+    let mut payload_lines = Vec::new();
+    let mut checksum_line = None;
+    for line in lines {
+        let trimmed = line.trim();
+        if trimmed == FOOTER {
+            saw_footer = true;
+            break;
+        }
+        if trimmed.is_empty() {
+            continue;
+        }
+        match trimmed.strip_prefix('=') {
+            Some(rest) => checksum_line = Some(rest.to_string()),
+            None => payload_lines.push(trimmed.to_string()),
+        }
+    }
+    if !saw_footer {
+        return Err(ArmorError::MalformedEnvelope);
+    }
+
+    let checksum_line = checksum_line.ok_or(ArmorError::MissingChecksum)?;
+    let payload = base64_decode(&payload_lines.concat())?;
+
+    let checksum_bytes = base64_decode(&checksum_line)?;
+    if checksum_bytes.len() != 3 {
+        return Err(ArmorError::InvalidEncoding);
+    }
+    let expected_checksum = ((checksum_bytes[0] as u32) << 16)
+        | ((checksum_bytes[1] as u32) << 8)
+        | (checksum_bytes[2] as u32);
+
+    if crc24(&payload) != expected_checksum {
+        return Err(ArmorError::ChecksumMismatch);
+    }
+
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{armor, dearmor, crc24, ArmorError};
+
+    #[test]
+    fn armor_dearmor_roundtrip() {
+        let payload = b"{\"transpiled\":true,\"bytecode\":\"deadbeef\"}".to_vec();
+        let armored = armor(&payload);
+        assert!(armored.starts_with("-----BEGIN AZTEC TRANSPILED CONTRACT-----"));
+        assert!(armored.trim_end().ends_with("-----END AZTEC TRANSPILED CONTRACT-----"));
+
+        let recovered = dearmor(&armored).expect("well-formed envelope should dearmor");
+        assert_eq!(recovered, payload);
+    }
+
+    #[test]
+    fn dearmor_detects_corruption() {
+        let payload = b"some contract json payload".to_vec();
+        let mut armored = armor(&payload);
+        // Flip a character in the payload body without touching the checksum line.
+        let body_start = armored.find("\n\n").unwrap() + 2;
+        let flipped_index = body_start + 1;
+        let bytes = unsafe { armored.as_bytes_mut() };
+        bytes[flipped_index] = if bytes[flipped_index] == b'A' { b'B' } else { b'A' };
+
+        assert!(matches!(dearmor(&armored), Err(ArmorError::ChecksumMismatch)));
+    }
+
+    #[test]
+    fn crc24_matches_known_test_vector() {
+        // The canonical "123456789" check value for this CRC-24 variant.
+        assert_eq!(crc24(b"123456789"), 0x0021CF02);
+    }
+}
@@ -10,6 +10,7 @@ use std::fs;
 use std::path::Path;
 use std::slice;
 
+mod armor;
 mod bit_traits;
 mod instructions;
 mod opcodes;
@@ -21,12 +22,32 @@ mod utils;
 pub use transpile::*;
 pub use transpile_contract::*;
 
+/// A stable discriminant for why a transpile call failed, so FFI consumers can branch on failure
+/// type instead of string-matching `error_message`. `None` means the call succeeded.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranspileErrorKind {
+    None = 0,
+    NullArgument,
+    InvalidUtf8,
+    IoRead,
+    IoWrite,
+    BackupFailed,
+    ParseWireFormat,
+    AlreadyTranspiled,
+    Serialize,
+    BufferTooSmall,
+    MalformedArmor,
+    ChecksumMismatch,
+}
+
 #[repr(C)]
 pub struct TranspileResult {
     pub success: c_int,
     pub data: *mut u8,
     pub length: size_t,
     pub error_message: *mut c_char,
+    pub error_kind: TranspileErrorKind,
 }
 
 impl Default for TranspileResult {
@@ -36,11 +57,12 @@ impl Default for TranspileResult {
             data: std::ptr::null_mut(),
             length: 0,
             error_message: std::ptr::null_mut(),
+            error_kind: TranspileErrorKind::None,
         }
     }
 }
 
-fn create_error_result(error: &str) -> TranspileResult {
+fn create_error_result(kind: TranspileErrorKind, error: &str) -> TranspileResult {
     let error_cstr = match CString::new(error) {
         Ok(cstr) => cstr,
         Err(_) => CString::new("Error message contains null bytes").unwrap(),
@@ -51,6 +73,7 @@ fn create_error_result(error: &str) -> TranspileResult {
         data: std::ptr::null_mut(),
         length: 0,
         error_message: error_cstr.into_raw(),
+        error_kind: kind,
     }
 }
 
@@ -58,7 +81,13 @@ fn create_success_result(data: Vec<u8>) -> TranspileResult {
     let length = data.len();
     let data_ptr = Box::into_raw(data.into_boxed_slice()) as *mut u8;
 
-    TranspileResult { success: 1, data: data_ptr, length, error_message: std::ptr::null_mut() }
+    TranspileResult {
+        success: 1,
+        data: data_ptr,
+        length,
+        error_message: std::ptr::null_mut(),
+        error_kind: TranspileErrorKind::None,
+    }
 }
 
 /// Transpile an Aztec contract from a file.
@@ -74,19 +103,26 @@ pub unsafe extern "C" fn avm_transpile_file(
     output_path: *const c_char,
 ) -> TranspileResult {
     if input_path.is_null() || output_path.is_null() {
-        return create_error_result("Input or output path is null");
+        return create_error_result(TranspileErrorKind::NullArgument, "Input or output path is null");
     }
 
     // SAFETY: Caller ensures input_path is valid null-terminated C string
     let input_path_str = match unsafe { CStr::from_ptr(input_path) }.to_str() {
         Ok(s) => s,
-        Err(_) => return create_error_result("Invalid UTF-8 in input path"),
+        Err(_) => {
+            return create_error_result(TranspileErrorKind::InvalidUtf8, "Invalid UTF-8 in input path")
+        }
     };
 
     // SAFETY: Caller ensures output_path is valid null-terminated C string
     let output_path_str = match unsafe { CStr::from_ptr(output_path) }.to_str() {
         Ok(s) => s,
-        Err(_) => return create_error_result("Invalid UTF-8 in output path"),
+        Err(_) => {
+            return create_error_result(
+                TranspileErrorKind::InvalidUtf8,
+                "Invalid UTF-8 in output path",
+            )
+        }
     };
 
     let json_parse_error = format!(
@@ -98,17 +134,20 @@ pub unsafe extern "C" fn avm_transpile_file(
     let contract_json = match fs::read_to_string(Path::new(input_path_str)) {
         Ok(content) => content,
         Err(e) => {
-            return create_error_result(&format!("Unable to read file {}: {}", input_path_str, e));
+            return create_error_result(
+                TranspileErrorKind::IoRead,
+                &format!("Unable to read file {}: {}", input_path_str, e),
+            );
         }
     };
 
     let raw_json_obj: serde_json::Value = match serde_json::from_str(&contract_json) {
         Ok(obj) => obj,
-        Err(_) => return create_error_result(&json_parse_error),
+        Err(_) => return create_error_result(TranspileErrorKind::ParseWireFormat, &json_parse_error),
     };
 
     if let Some(serde_json::Value::Bool(true)) = raw_json_obj.get("transpiled") {
-        return create_error_result("Contract already transpiled");
+        return create_error_result(TranspileErrorKind::AlreadyTranspiled, "Contract already transpiled");
     }
 
     if Path::new(output_path_str).exists() {
@@ -116,31 +155,63 @@ pub unsafe extern "C" fn avm_transpile_file(
             Path::new(output_path_str),
             Path::new(&(output_path_str.to_string() + ".bak")),
         ) {
-            return create_error_result(&format!(
-                "Unable to backup file {}: {}",
-                output_path_str, e
-            ));
+            return create_error_result(
+                TranspileErrorKind::BackupFailed,
+                &format!("Unable to backup file {}: {}", output_path_str, e),
+            );
         }
     }
 
     let contract: CompiledAcirContractArtifact = match serde_json::from_str(&contract_json) {
         Ok(contract) => contract,
-        Err(_) => return create_error_result(&json_parse_error),
+        Err(_) => return create_error_result(TranspileErrorKind::ParseWireFormat, &json_parse_error),
     };
 
     let transpiled_contract = TranspiledContractArtifact::from(contract);
     let transpiled_json = match serde_json::to_string(&transpiled_contract) {
         Ok(json) => json,
-        Err(e) => return create_error_result(&format!("Unable to serialize json: {}", e)),
+        Err(e) => {
+            return create_error_result(
+                TranspileErrorKind::Serialize,
+                &format!("Unable to serialize json: {}", e),
+            )
+        }
     };
 
     if let Err(e) = fs::write(output_path_str, &transpiled_json) {
-        return create_error_result(&format!("Unable to write file: {}", e));
+        return create_error_result(
+            TranspileErrorKind::IoWrite,
+            &format!("Unable to write file: {}", e),
+        );
     }
 
     create_success_result(transpiled_json.into_bytes())
 }
 
+/// Parses `contract_json`, checks the idempotent "already transpiled" short-circuit, and runs the
+/// transpiler, returning the transpiled JSON. Shared by `avm_transpile_bytecode` and
+/// `avm_transpile_bytecode_into`.
+fn transpile_contract_json(contract_json: &str) -> Result<String, (TranspileErrorKind, String)> {
+    let json_parse_error = "Unable to parse input json. This is probably a stale json file with a different wire format.";
+
+    let raw_json_obj: serde_json::Value = serde_json::from_str(contract_json)
+        .map_err(|_| (TranspileErrorKind::ParseWireFormat, json_parse_error.to_string()))?;
+
+    if let Some(serde_json::Value::Bool(true)) = raw_json_obj.get("transpiled") {
+        return Err((
+            TranspileErrorKind::AlreadyTranspiled,
+            "Contract already transpiled".to_string(),
+        ));
+    }
+
+    let contract: CompiledAcirContractArtifact = serde_json::from_str(contract_json)
+        .map_err(|_| (TranspileErrorKind::ParseWireFormat, json_parse_error.to_string()))?;
+
+    let transpiled_contract = TranspiledContractArtifact::from(contract);
+    serde_json::to_string(&transpiled_contract)
+        .map_err(|e| (TranspileErrorKind::Serialize, format!("Unable to serialize json: {}", e)))
+}
+
 /// Transpile an Aztec contract from bytecode.
 ///
 /// # Safety
@@ -153,39 +224,240 @@ pub unsafe extern "C" fn avm_transpile_bytecode(
     input_length: size_t,
 ) -> TranspileResult {
     if input_data.is_null() {
-        return create_error_result("Input data is null");
+        return create_error_result(TranspileErrorKind::NullArgument, "Input data is null");
     }
 
     // SAFETY: Caller ensures input_data points to valid memory of input_length bytes
     let input_slice = unsafe { slice::from_raw_parts(input_data, input_length) };
     let contract_json = match String::from_utf8(input_slice.to_vec()) {
         Ok(json) => json,
-        Err(_) => return create_error_result("Input data is not valid UTF-8"),
+        Err(_) => {
+            return create_error_result(TranspileErrorKind::InvalidUtf8, "Input data is not valid UTF-8")
+        }
     };
 
-    let json_parse_error = "Unable to parse input json. This is probably a stale json file with a different wire format.";
+    match transpile_contract_json(&contract_json) {
+        Ok(transpiled_json) => create_success_result(transpiled_json.into_bytes()),
+        Err((kind, message)) => create_error_result(kind, &message),
+    }
+}
 
-    let raw_json_obj: serde_json::Value = match serde_json::from_str(&contract_json) {
-        Ok(obj) => obj,
-        Err(_) => return create_error_result(json_parse_error),
+/// Transpile an Aztec contract from bytecode directly into a caller-provided buffer, avoiding the
+/// heap allocation and `avm_free_result` round-trip `avm_transpile_bytecode` requires.
+///
+/// Always writes the required output length to `*out_written` once parsing/transpilation
+/// succeeds. If `out_cap` is smaller than that length, no bytes are copied into `out_buf` and the
+/// function returns `TranspileErrorKind::BufferTooSmall`, so the caller can reallocate to
+/// `*out_written` bytes and retry.
+///
+/// # Safety
+///
+/// - `input_data` must be a valid pointer to a buffer of `input_length` bytes
+/// - `out_buf` must be null (only valid if `out_cap` is 0) or point to a buffer of at least
+///   `out_cap` bytes
+/// - `out_written` must be a valid pointer to a `size_t`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn avm_transpile_bytecode_into(
+    input_data: *const u8,
+    input_length: size_t,
+    out_buf: *mut u8,
+    out_cap: size_t,
+    out_written: *mut size_t,
+) -> TranspileErrorKind {
+    if input_data.is_null() || out_written.is_null() {
+        return TranspileErrorKind::NullArgument;
+    }
+
+    // SAFETY: Caller ensures input_data points to valid memory of input_length bytes
+    let input_slice = unsafe { slice::from_raw_parts(input_data, input_length) };
+    let contract_json = match String::from_utf8(input_slice.to_vec()) {
+        Ok(json) => json,
+        Err(_) => return TranspileErrorKind::InvalidUtf8,
     };
 
-    if let Some(serde_json::Value::Bool(true)) = raw_json_obj.get("transpiled") {
-        return create_error_result("Contract already transpiled");
+    let transpiled_json = match transpile_contract_json(&contract_json) {
+        Ok(json) => json,
+        Err((kind, _message)) => return kind,
+    };
+
+    let required_len = transpiled_json.len();
+    // SAFETY: caller ensures out_written is a valid pointer
+    unsafe {
+        *out_written = required_len;
     }
 
-    let contract: CompiledAcirContractArtifact = match serde_json::from_str(&contract_json) {
-        Ok(contract) => contract,
-        Err(_) => return create_error_result(json_parse_error),
-    };
+    if out_cap < required_len {
+        return TranspileErrorKind::BufferTooSmall;
+    }
 
-    let transpiled_contract = TranspiledContractArtifact::from(contract);
-    let transpiled_json = match serde_json::to_string(&transpiled_contract) {
+    if required_len > 0 {
+        if out_buf.is_null() {
+            return TranspileErrorKind::NullArgument;
+        }
+        // SAFETY: caller ensures out_buf points to a buffer of at least out_cap >= required_len bytes
+        unsafe {
+            std::ptr::copy_nonoverlapping(transpiled_json.as_ptr(), out_buf, required_len);
+        }
+    }
+
+    TranspileErrorKind::None
+}
+
+/// Transpiles `count` contracts in one FFI crossing, reusing the same parse/transpile pipeline as
+/// `avm_transpile_bytecode` for each one. Partial failure is per-element: element *i*'s own
+/// `TranspileResult` carries its own error code rather than aborting the whole batch.
+///
+/// # Safety
+///
+/// - `inputs` must be a valid pointer to `count` pointers, each either null or pointing to a
+///   buffer of the corresponding `lengths[i]` bytes
+/// - `lengths` must be a valid pointer to `count` `size_t`s
+/// - Both arrays, and the buffers they point to, must remain valid for the duration of this call
+/// - The returned pointer must be freed exactly once, with `avm_free_batch_results` passing the
+///   same `count`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn avm_transpile_batch(
+    inputs: *const *const u8,
+    lengths: *const size_t,
+    count: size_t,
+) -> *mut TranspileResult {
+    if inputs.is_null() || lengths.is_null() {
+        let results: Vec<TranspileResult> = (0..count)
+            .map(|_| create_error_result(TranspileErrorKind::NullArgument, "Input array is null"))
+            .collect();
+        return Box::into_raw(results.into_boxed_slice()) as *mut TranspileResult;
+    }
+
+    // SAFETY: caller guarantees inputs/lengths point to `count`-length arrays
+    let input_ptrs = unsafe { slice::from_raw_parts(inputs, count) };
+    let lengths = unsafe { slice::from_raw_parts(lengths, count) };
+
+    let results: Vec<TranspileResult> = input_ptrs
+        .iter()
+        .zip(lengths.iter())
+        .map(|(&input_ptr, &length)| {
+            if input_ptr.is_null() {
+                return create_error_result(TranspileErrorKind::NullArgument, "Input data is null");
+            }
+
+            // SAFETY: caller guarantees input_ptr points to `length` valid bytes
+            let input_slice = unsafe { slice::from_raw_parts(input_ptr, length) };
+            let contract_json = match String::from_utf8(input_slice.to_vec()) {
+                Ok(json) => json,
+                Err(_) => {
+                    return create_error_result(
+                        TranspileErrorKind::InvalidUtf8,
+                        "Input data is not valid UTF-8",
+                    )
+                }
+            };
+
+            match transpile_contract_json(&contract_json) {
+                Ok(transpiled_json) => create_success_result(transpiled_json.into_bytes()),
+                Err((kind, message)) => create_error_result(kind, &message),
+            }
+        })
+        .collect();
+
+    Box::into_raw(results.into_boxed_slice()) as *mut TranspileResult
+}
+
+/// Frees an array of `count` results returned by `avm_transpile_batch`.
+///
+/// # Safety
+///
+/// - `ptr` must be a pointer previously returned by `avm_transpile_batch` called with the same
+///   `count`
+/// - The array must not be used after calling this function
+/// - This function must be called exactly once per array
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn avm_free_batch_results(ptr: *mut TranspileResult, count: size_t) {
+    if ptr.is_null() {
+        return;
+    }
+
+    // SAFETY: caller ensures ptr/count match a `Box<[TranspileResult]>` produced by
+    // avm_transpile_batch via Box::into_raw
+    let mut results = unsafe { Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, count)) };
+    for result in results.iter_mut() {
+        // SAFETY: each element's data/error_message pointers were populated the same way as a
+        // single-result avm_transpile_* call, so avm_free_result's contract applies per-element
+        unsafe { avm_free_result(result) };
+    }
+}
+
+/// Transpile an Aztec contract from bytecode, wrapping the result in an ASCII-armored,
+/// CRC-24-checked envelope (see the [`armor`] module) instead of raw JSON bytes. Useful for
+/// transmitting the artifact over text-only channels (logs, copy-paste, CI artifact stores).
+///
+/// # Safety
+///
+/// Same as `avm_transpile_bytecode`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn avm_transpile_bytecode_armored(
+    input_data: *const u8,
+    input_length: size_t,
+) -> TranspileResult {
+    if input_data.is_null() {
+        return create_error_result(TranspileErrorKind::NullArgument, "Input data is null");
+    }
+
+    // SAFETY: Caller ensures input_data points to valid memory of input_length bytes
+    let input_slice = unsafe { slice::from_raw_parts(input_data, input_length) };
+    let contract_json = match String::from_utf8(input_slice.to_vec()) {
         Ok(json) => json,
-        Err(e) => return create_error_result(&format!("Unable to serialize json: {}", e)),
+        Err(_) => {
+            return create_error_result(TranspileErrorKind::InvalidUtf8, "Input data is not valid UTF-8")
+        }
     };
 
-    create_success_result(transpiled_json.into_bytes())
+    match transpile_contract_json(&contract_json) {
+        Ok(transpiled_json) => {
+            let armored = armor::armor(transpiled_json.as_bytes());
+            create_success_result(armored.into_bytes())
+        }
+        Err((kind, message)) => create_error_result(kind, &message),
+    }
+}
+
+/// Verifies and unwraps an envelope produced by `avm_transpile_bytecode_armored`, returning the
+/// original transpiled JSON bytes. Surfaces `TranspileErrorKind::ChecksumMismatch` on a CRC-24
+/// mismatch and `TranspileErrorKind::MalformedArmor` on a structurally invalid envelope, giving
+/// callers corruption detection independent of JSON validity.
+///
+/// # Safety
+///
+/// - `armored_data` must be a valid pointer to a buffer of `armored_length` bytes
+/// - The buffer must remain valid for the duration of this call
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn avm_dearmor_transpiled_contract(
+    armored_data: *const u8,
+    armored_length: size_t,
+) -> TranspileResult {
+    if armored_data.is_null() {
+        return create_error_result(TranspileErrorKind::NullArgument, "Armored data is null");
+    }
+
+    // SAFETY: Caller ensures armored_data points to valid memory of armored_length bytes
+    let armored_slice = unsafe { slice::from_raw_parts(armored_data, armored_length) };
+    let armored_text = match std::str::from_utf8(armored_slice) {
+        Ok(text) => text,
+        Err(_) => {
+            return create_error_result(
+                TranspileErrorKind::InvalidUtf8,
+                "Armored data is not valid UTF-8",
+            )
+        }
+    };
+
+    match armor::dearmor(armored_text) {
+        Ok(payload) => create_success_result(payload),
+        Err(armor::ArmorError::ChecksumMismatch) => create_error_result(
+            TranspileErrorKind::ChecksumMismatch,
+            "Armor checksum mismatch: payload is corrupted",
+        ),
+        Err(e) => create_error_result(TranspileErrorKind::MalformedArmor, &e.to_string()),
+    }
 }
 
 /// Free memory allocated by transpile functions.
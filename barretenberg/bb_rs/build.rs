@@ -1,69 +1,117 @@
 use bindgen::Builder;
 use cmake::Config;
 use needs_rebuild::{needs_rebuild, ScanOptions};
+use serde::Deserialize;
 use std::env;
 use std::path::PathBuf;
 use std::process::Command;
 
-/// Fix duplicate type definitions in the generated bindings file
-/// It's known bug with bindgen that generates duplicate type definitions
-/// if they are defined in multiple templates.
-/// It's easier to just post-process the bindings file to remove the duplicate type definitions,
-/// rather than trying to patch for it in the C++ code.
+/// The declarative contents of `bindings.toml`: the set of C++ functions to generate bindings
+/// for, kept out of `build.rs` so the FFI surface can be reviewed and diffed on its own.
+#[derive(Debug, Deserialize)]
+struct BindingsConfig {
+    functions: Vec<String>,
+}
+
+fn load_bindings_config() -> BindingsConfig {
+    let contents = std::fs::read_to_string("bindings.toml")
+        .expect("Failed to read bindings.toml");
+    toml::from_str(&contents).expect("Failed to parse bindings.toml")
+}
+
+/// A single entry in the cross-compilation target matrix (`targets.toml`).
+#[derive(Debug, Deserialize)]
+struct CrossCompileTarget {
+    generator: String,
+    build_target: String,
+    configure_args: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TargetsConfig {
+    targets: std::collections::HashMap<String, CrossCompileTarget>,
+}
+
+fn load_targets_config() -> TargetsConfig {
+    let contents =
+        std::fs::read_to_string("targets.toml").expect("Failed to read targets.toml");
+    toml::from_str(&contents).expect("Failed to parse targets.toml")
+}
+
+/// Substitutes `{VAR_NAME}` placeholders in a configure arg with the matching environment
+/// variable, so `targets.toml` doesn't have to hardcode a machine's SDK paths.
+fn substitute_env_placeholders(arg: &str) -> String {
+    let mut result = arg.to_string();
+    while let (Some(start), Some(end)) = (result.find('{'), result.find('}')) {
+        if end < start {
+            break;
+        }
+        let var_name = &result[start + 1..end];
+        let value = env::var(var_name)
+            .unwrap_or_else(|_| panic!("environment variable {} not set", var_name));
+        result.replace_range(start..=end, &value);
+    }
+    result
+}
+
+/// Strips duplicate top-level `type`/`struct`/`union` definitions bindgen sometimes emits when
+/// the same C++ type is reachable through more than one included template.
+///
+/// This used to shell out to `scripts/fix_bindings.py` or `scripts/fix_bindings.sh`, which
+/// silently no-ops (just a `cargo:warning`) when neither interpreter is on `PATH` -- a real
+/// failure mode on minimal CI images and Windows. Doing the rewrite here instead keeps it
+/// deterministic and dependency-free.
 fn fix_duplicate_bindings(bindings_file: &PathBuf) {
     println!("cargo:warning=Fixing duplicate type definitions in bindings...");
 
-    let scripts_dir = PathBuf::from("scripts");
-    let python_script = scripts_dir.join("fix_bindings.py");
-    let shell_script = scripts_dir.join("fix_bindings.sh");
-
-    // Try Python script first
-    if python_script.exists() {
-        let output = Command::new("python3")
-            .arg(&python_script)
-            .arg(bindings_file)
-            .output();
-
-        match output {
-            Ok(result) => {
-                if result.status.success() {
-                    println!("cargo:warning=Successfully fixed bindings with Python script");
-                    return;
-                } else {
-                    println!("cargo:warning=Python script failed, trying shell script...");
-                }
-            }
-            Err(_) => {
-                println!("cargo:warning=Python not available, trying shell script...");
+    let contents = std::fs::read_to_string(bindings_file)
+        .expect("Failed to read generated bindings for duplicate-definition fixup");
+
+    let mut seen = std::collections::HashSet::new();
+    let mut output = String::with_capacity(contents.len());
+    let mut lines = contents.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        if let Some(name) = top_level_definition_name(line) {
+            if !seen.insert(name) {
+                skip_definition_body(line, &mut lines);
+                continue;
             }
         }
+        output.push_str(line);
+        output.push('\n');
     }
 
-    // Fallback to shell script
-    if shell_script.exists() {
-        let output = Command::new("bash")
-            .arg(&shell_script)
-            .arg(bindings_file)
-            .output();
-
-        match output {
-            Ok(result) => {
-                if result.status.success() {
-                    println!("cargo:warning=Successfully fixed bindings with shell script");
-                } else {
-                    println!("cargo:warning=Shell script failed");
-                    eprintln!(
-                        "Shell script stderr: {}",
-                        String::from_utf8_lossy(&result.stderr)
-                    );
-                }
+    std::fs::write(bindings_file, output)
+        .expect("Failed to write de-duplicated bindings file");
+}
+
+/// Returns a unique key for the item `line` defines, if it starts a top-level `type`, `struct`,
+/// or `union` definition.
+fn top_level_definition_name(line: &str) -> Option<String> {
+    let trimmed = line.trim_start();
+    for prefix in ["pub type ", "pub struct ", "pub union "] {
+        if let Some(rest) = trimmed.strip_prefix(prefix) {
+            let name = rest.split(|c: char| !(c.is_alphanumeric() || c == '_')).next()?;
+            if !name.is_empty() {
+                return Some(format!("{}{}", prefix, name));
             }
-            Err(e) => {
-                println!("cargo:warning=Failed to run shell script: {}", e);
+        }
+    }
+    None
+}
+
+/// Consumes the remainder of a skipped definition: a brace-delimited body for `struct`/`union`,
+/// or nothing more for a single-line `type` alias.
+fn skip_definition_body<'a>(first_line: &str, lines: &mut std::iter::Peekable<std::str::Lines<'a>>) {
+    let mut depth = first_line.matches('{').count() as i32 - first_line.matches('}').count() as i32;
+    while depth > 0 {
+        match lines.next() {
+            Some(line) => {
+                depth += line.matches('{').count() as i32 - line.matches('}').count() as i32;
             }
+            None => break,
         }
-    } else {
-        println!("cargo:warning=No fix scripts found, skipping duplicate removal");
     }
 }
 
@@ -72,57 +120,44 @@ fn dep_include(dep_name: &str, dst: &PathBuf) -> String {
 }
 
 fn build_lib(target_os: &str) -> PathBuf {
-    // Build the C++ code using CMake and get the build directory path.
-    let dst;
-    // iOS
-    if target_os == "ios" {
-        dst = Config::new("../cpp")
-            .generator("Ninja")
-            .configure_arg("-DCMAKE_BUILD_TYPE=Release")
-            .configure_arg("-DPLATFORM=OS64")
-            .configure_arg("-DDEPLOYMENT_TARGET=15.0")
-            .configure_arg("--toolchain=../bb_rs/ios.toolchain.cmake")
-            .configure_arg("-DTRACY_ENABLE=OFF")
-            .build_target("bb")
-            .build();
-    }
-    // Android
-    else if target_os == "android" {
-        let android_home = option_env!("ANDROID_HOME").expect("ANDROID_HOME not set");
-        let ndk_version = option_env!("NDK_VERSION").expect("NDK_VERSION not set");
+    let targets_config = load_targets_config();
+    // Cargo already budgeted this much parallelism for us; pass it through instead of letting
+    // the native build default to an implicit (often serial) job count.
+    let jobs = env::var("NUM_JOBS").ok();
 
-        dst = Config::new("../cpp")
-            .generator("Ninja")
-            .configure_arg("-DCMAKE_BUILD_TYPE=Release")
-            .configure_arg("-DANDROID_ABI=arm64-v8a")
-            .configure_arg("-DANDROID_PLATFORM=android-33")
-            .configure_arg(&format!(
-                "--toolchain={}/ndk/{}/build/cmake/android.toolchain.cmake",
-                android_home, ndk_version
-            ))
-            .configure_arg("-DTRACY_ENABLE=OFF")
-            .build_target("bb")
-            .build();
-    }
-    // MacOS and other platforms
-    else {
-        let cmd = Command::new("./scripts/build_bb.sh")
-            .output()
-            .expect("Failed to execute build_cpp.sh");
+    // Targets declared in `targets.toml` are built directly via CMake; anything else (macOS,
+    // Linux) falls back to the native `build_bb.sh` script.
+    if let Some(target) = targets_config.targets.get(target_os) {
+        let mut config = Config::new("../cpp");
+        config.generator(&target.generator);
+        for arg in &target.configure_args {
+            config.configure_arg(&substitute_env_placeholders(arg));
+        }
+        config.build_target(&target.build_target);
+        if let Some(jobs) = &jobs {
+            config.build_arg(format!("--parallel={}", jobs));
+        }
+        config.build()
+    } else {
+        let mut command = Command::new("./scripts/build_bb.sh");
+        if let Some(jobs) = &jobs {
+            command.env("CMAKE_BUILD_PARALLEL_LEVEL", jobs);
+        }
+        let cmd = command.output().expect("Failed to execute build_cpp.sh");
         if !cmd.status.success() {
             panic!(
                 "build_cpp.sh failed with error: {}",
                 String::from_utf8_lossy(&cmd.stderr)
             );
         }
-        dst = PathBuf::from("../cpp");
+        PathBuf::from("../cpp")
     }
-    dst
 }
 
 fn main() {
     // Notify Cargo to rerun this build script if `build.rs` changes
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=targets.toml");
 
     // cfg!(target_os = "<os>") does not work so we get the value
     // of the target_os environment variable to determine the target OS.
@@ -250,32 +285,17 @@ fn main() {
             ]);
     }
 
-    let bindings = builder
+    println!("cargo:rerun-if-changed=bindings.toml");
+    let bindings_config = load_bindings_config();
+
+    builder = builder
         // The input header we would like to generate bindings for.
-        .header_contents("wrapper.hpp", include_str!("./wrapper.hpp"))
-        .allowlist_function("bbapi_set_verbose_logging")
-        .allowlist_function("bbapi_set_debug_logging")
-        .allowlist_function("bbapi_non_chonk")
-        .allowlist_function("bbapi_init")
-        .allowlist_function("bbapi_cleanup")
-        .allowlist_function("bbapi_free_result")
-        .allowlist_function("srs_init_srs")
-        .allowlist_function("acir_get_circuit_sizes")
-        .allowlist_function("acir_serialize_proof_into_fields")
-        .allowlist_function("acir_serialize_verification_key_into_fields")
-        .allowlist_function("acir_prove_ultra_honk")
-        .allowlist_function("acir_prove_ultra_keccak_honk")
-        .allowlist_function("acir_prove_ultra_keccak_zk_honk")
-        .allowlist_function("acir_prove_aztec_client")
-        .allowlist_function("acir_verify_ultra_honk")
-        .allowlist_function("acir_verify_ultra_keccak_honk")
-        .allowlist_function("acir_verify_ultra_keccak_zk_honk")
-        .allowlist_function("acir_verify_aztec_client")
-        .allowlist_function("acir_write_vk_ultra_honk")
-        .allowlist_function("acir_write_vk_ultra_keccak_honk")
-        .allowlist_function("acir_write_vk_ultra_keccak_zk_honk")
-        .allowlist_function("acir_prove_and_verify_ultra_honk")
-        .allowlist_function("bbapi")
+        .header_contents("wrapper.hpp", include_str!("./wrapper.hpp"));
+    for function in &bindings_config.functions {
+        builder = builder.allowlist_function(function);
+    }
+
+    let bindings = builder
         // Tell cargo to invalidate the built crate whenever any of the included header files changed.
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
         // Finish the builder and generate the bindings.
@@ -4,6 +4,15 @@ use super::{
 };
 use std::ptr;
 
+const BLOCK_SIZE: usize = 16;
+
+/// Errors produced by the padding/stream-cipher-aware AES wrappers.
+#[derive(Debug, thiserror::Error)]
+pub enum AesError {
+    #[error("invalid PKCS#7 padding")]
+    InvalidPadding,
+}
+
 /// AES-128 CBC encryption
 /// Expects input to be already padded (PKCS#7 padding should be applied at the TypeScript layer)
 pub unsafe fn aes_encrypt_buffer_cbc(
@@ -91,3 +100,106 @@ pub unsafe fn aes_decrypt_buffer_cbc(
     // Padding removal should be handled at the TypeScript layer
     Buffer::from_data(actual_decrypted_data.to_vec())
 }
+
+fn pkcs7_pad(input: &[u8]) -> Vec<u8> {
+    let padding_len = BLOCK_SIZE - (input.len() % BLOCK_SIZE);
+    let mut padded = input.to_vec();
+    padded.extend(std::iter::repeat(padding_len as u8).take(padding_len));
+    padded
+}
+
+fn pkcs7_unpad(data: &[u8]) -> Result<Vec<u8>, AesError> {
+    let padding_len = *data.last().ok_or(AesError::InvalidPadding)? as usize;
+    if padding_len == 0 || padding_len > BLOCK_SIZE || padding_len > data.len() {
+        return Err(AesError::InvalidPadding);
+    }
+    if data[data.len() - padding_len..]
+        .iter()
+        .any(|&b| b as usize != padding_len)
+    {
+        return Err(AesError::InvalidPadding);
+    }
+    Ok(data[..data.len() - padding_len].to_vec())
+}
+
+/// AES-128 CBC encryption with PKCS#7 padding applied internally, so callers needn't pre-pad
+/// `input` to a 16-byte boundary themselves.
+///
+/// # Safety
+///
+/// See [`aes_encrypt_buffer_cbc`].
+pub unsafe fn aes_encrypt_cbc_pkcs7(input: &[u8], iv: &[u8; 16], key: &[u8; 16]) -> Buffer {
+    let padded = pkcs7_pad(input);
+    unsafe { aes_encrypt_buffer_cbc(&padded, iv, key) }
+}
+
+/// AES-128 CBC decryption with PKCS#7 padding removed internally, rejecting invalid padding with
+/// [`AesError::InvalidPadding`] instead of panicking.
+///
+/// # Safety
+///
+/// See [`aes_decrypt_buffer_cbc`].
+pub unsafe fn aes_decrypt_cbc_pkcs7(
+    input: &[u8],
+    iv: &[u8; 16],
+    key: &[u8; 16],
+) -> Result<Buffer, AesError> {
+    let decrypted = unsafe { aes_decrypt_buffer_cbc(input, iv, key) };
+    let unpadded = pkcs7_unpad(decrypted.as_slice())?;
+    Ok(Buffer::from_data(unpadded))
+}
+
+/// Increments a 128-bit big-endian counter by one, with wraparound on overflow.
+fn increment_counter(counter: &mut [u8; 16]) {
+    for byte in counter.iter_mut().rev() {
+        *byte = byte.wrapping_add(1);
+        if *byte != 0 {
+            break;
+        }
+    }
+}
+
+/// XORs `input` against the CTR-mode keystream derived from `iv`: block *i*'s keystream is
+/// `E_k(nonce || counter_i)`, computed here as a single-block CBC-encrypt with a zero IV (CBC
+/// degenerates to ECB on a lone block), with the 128-bit IV incremented per block. The final
+/// (possibly partial) block is XORed with only the leading bytes of its keystream block, so
+/// `input` needs no padding to a block boundary.
+///
+/// # Safety
+///
+/// See [`aes_encrypt_buffer_cbc`].
+unsafe fn aes_ctr_xor(input: &[u8], iv: &[u8; 16], key: &[u8; 16]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(input.len());
+    let mut counter = *iv;
+    let zero_iv = [0u8; 16];
+
+    for chunk in input.chunks(BLOCK_SIZE) {
+        let keystream_block = unsafe { aes_encrypt_buffer_cbc(&counter, &zero_iv, key) };
+        for (byte, ks_byte) in chunk.iter().zip(keystream_block.as_slice()) {
+            output.push(byte ^ ks_byte);
+        }
+        increment_counter(&mut counter);
+    }
+
+    output
+}
+
+/// AES-128 CTR mode encryption. Turns the block cipher into a stream cipher, so `input` can be
+/// any length with no padding required.
+///
+/// # Safety
+///
+/// See [`aes_encrypt_buffer_cbc`].
+pub unsafe fn aes_encrypt_ctr(input: &[u8], iv: &[u8; 16], key: &[u8; 16]) -> Vec<u8> {
+    unsafe { aes_ctr_xor(input, iv, key) }
+}
+
+/// AES-128 CTR mode decryption. Identical to [`aes_encrypt_ctr`]: CTR mode XORs the same
+/// keystream onto the input in both directions.
+///
+/// # Safety
+///
+/// See [`aes_decrypt_buffer_cbc`].
+pub unsafe fn aes_decrypt_ctr(input: &[u8], iv: &[u8; 16], key: &[u8; 16]) -> Vec<u8> {
+    unsafe { aes_ctr_xor(input, iv, key) }
+}
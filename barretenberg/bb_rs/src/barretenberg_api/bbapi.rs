@@ -1,5 +1,5 @@
 use super::bindgen;
-use crate::noir_api::artifacts::{load_binary, save_binary};
+use crate::noir_api::artifacts::{read_container, write_container, Codec, PayloadKind};
 use log::*;
 use num_bigint::BigUint;
 use rmp_serde::{decode, encode};
@@ -8,6 +8,9 @@ use std::os::raw::c_void;
 use std::path::Path;
 use std::ptr;
 use std::ptr::null;
+use std::sync::OnceLock;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 // This is not used for now, but may replace the acir functions later
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -31,7 +34,7 @@ pub struct CircuitInput {
     pub verification_key: Vec<u8>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProofSystemSettings {
     #[serde(default)]
     pub ipa_accumulation: bool,
@@ -43,13 +46,63 @@ pub struct ProofSystemSettings {
     pub optimized_solidity_verifier: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Uint256(#[serde(with = "serde_bytes")] [u8; 32]);
+/// A 32-byte big-endian field element.
+///
+/// Serializes as raw bytes for wire formats like msgpack (`is_human_readable() == false`), and as
+/// a `0x`-prefixed big-endian hex string for human-readable formats like JSON, so proofs exported
+/// with [`CircuitProveResponse::to_json`] are consumable by JS/Solidity tooling without a custom
+/// decoder.
+#[derive(Debug, Clone)]
+pub struct Uint256([u8; 32]);
 
 impl Uint256 {
     pub fn as_bigint(&self) -> BigUint {
         BigUint::from_bytes_be(&self.0)
     }
+
+    /// `0x`-prefixed big-endian hex representation.
+    pub fn to_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.0))
+    }
+
+    /// Parses a `0x`-prefixed (or bare) big-endian hex string back into a `Uint256`.
+    pub fn from_hex(s: &str) -> Result<Self, BbApiError> {
+        let digits = s.strip_prefix("0x").unwrap_or(s);
+        let bytes = hex::decode(digits).map_err(|e| BbApiError::ApiError(e.to_string()))?;
+        let array: [u8; 32] = bytes.try_into().map_err(|bytes: Vec<u8>| {
+            BbApiError::ApiError(format!(
+                "Uint256 hex must decode to 32 bytes, got {}",
+                bytes.len()
+            ))
+        })?;
+        Ok(Self(array))
+    }
+}
+
+impl Serialize for Uint256 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_hex())
+        } else {
+            serde_bytes::Bytes::new(&self.0).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Uint256 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let hex_string = String::deserialize(deserializer)?;
+            Uint256::from_hex(&hex_string).map_err(serde::de::Error::custom)
+        } else {
+            let bytes = serde_bytes::ByteBuf::deserialize(deserializer)?.into_vec();
+            let len = bytes.len();
+            let array: [u8; 32] = bytes
+                .try_into()
+                .map_err(|_| serde::de::Error::custom(format!("expected 32 bytes, got {}", len)))?;
+            Ok(Self(array))
+        }
+    }
 }
 
 fn default_oracle_hash_type() -> String {
@@ -81,21 +134,74 @@ pub struct CircuitProveResponse {
     pub public_inputs: Vec<Uint256>,
     pub proof: Vec<Uint256>,
     pub vk: CircuitComputeVkResponse,
+    /// The settings this proof was produced under. Recorded by the Rust-side prover wrappers
+    /// after the FFI response comes back (the C++ side doesn't echo it), so
+    /// [`aggregate_ultra_honk`] can reject folding proofs produced under mismatched transcript
+    /// settings.
+    #[serde(skip)]
+    pub settings: ProofSystemSettings,
 }
 
 impl CircuitProveResponse {
+    /// Saves this response as a versioned, self-describing container, so a later `load` can tell
+    /// it's a proof (and not e.g. a witness) before attempting to deserialize it.
+    ///
+    /// Dispatches on `path`'s extension: `.json` writes the portable JSON form from
+    /// [`Self::to_json`]; anything else writes the msgpack container, gzip-compressed when `path`
+    /// ends with `.gz`, matching the old convention.
     pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), BbApiError> {
+        if path.as_ref().extension().map(|ext| ext == "json").unwrap_or(false) {
+            std::fs::write(path, self.to_json()?)?;
+            return Ok(());
+        }
         let bytes = rmp_serde::to_vec_named(self)?;
-        // Will automatically compress if path ends with .gz
-        save_binary(path, &bytes)?;
+        let codec = if path.as_ref().extension().map(|ext| ext == "gz").unwrap_or(false) {
+            Codec::Gzip
+        } else {
+            Codec::None
+        };
+        write_container(path, PayloadKind::Proof, codec, &bytes)?;
         Ok(())
     }
 
+    /// Loads a response saved by [`Self::save`].
+    ///
+    /// Dispatches on `path`'s extension like `save`; `.json` files go through [`Self::from_json`].
+    /// Otherwise falls back to treating the file as a bare msgpack blob (the pre-container format)
+    /// when the container magic is absent, so older saved proofs still load.
     pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, BbApiError> {
-        let bytes = load_binary(path)?;
+        if path.as_ref().extension().map(|ext| ext == "json").unwrap_or(false) {
+            let json = std::fs::read_to_string(path)?;
+            return Self::from_json(&json);
+        }
+        let (kind, bytes) = read_container(path)?;
+        if !matches!(kind, PayloadKind::Proof | PayloadKind::Unknown) {
+            return Err(BbApiError::InvalidResponse {
+                expected: "proof".to_string(),
+                actual: format!("{:?}", kind),
+            });
+        }
         let response: CircuitProveResponse = rmp_serde::from_slice(&bytes)?;
         Ok(response)
     }
+
+    /// Serializes this response to the portable JSON form, with `public_inputs`/`proof` as
+    /// `0x`-prefixed big-endian hex strings, for consumption by JS/Solidity tooling.
+    pub fn to_json(&self) -> Result<String, BbApiError> {
+        serde_json::to_string_pretty(self).map_err(|e| BbApiError::ApiError(e.to_string()))
+    }
+
+    /// Parses a response previously serialized by [`Self::to_json`].
+    pub fn from_json(json: &str) -> Result<Self, BbApiError> {
+        serde_json::from_str(json).map_err(|e| BbApiError::ApiError(e.to_string()))
+    }
+
+    /// Returns `(public_inputs, proof)` as `0x`-prefixed big-endian hex strings.
+    pub fn to_hex_fields(&self) -> (Vec<String>, Vec<String>) {
+        let public_inputs = self.public_inputs.iter().map(Uint256::to_hex).collect();
+        let proof = self.proof.iter().map(Uint256::to_hex).collect();
+        (public_inputs, proof)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -127,6 +233,20 @@ pub struct CircuitComputeVkResponse {
     pub hash: Vec<u8>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CircuitWriteSolidityVerifier {
+    pub circuit: CircuitInputNoVK,
+    pub settings: ProofSystemSettings,
+}
+
+/// An on-chain verifier contract emitted by [`BbContext::get_ultra_keccak_honk_solidity_verifier`].
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CircuitWriteSolidityVerifierResponse {
+    pub contract: String,
+    #[serde(with = "serde_bytes")]
+    pub vk_hash: Vec<u8>,
+}
+
 // Error handling
 #[derive(Debug, thiserror::Error)]
 pub enum BbApiError {
@@ -245,16 +365,14 @@ where
 /// * Thread Safety: The call to bindgen::bbapi must be thread-safe. If it relies on static or global state without
 /// synchronization, calling it from multiple threads could cause data races.
 /// * No Panics: The C code must not panic or unwind across the FFI boundary.
+///
+/// Assumes a [`BbContext`] has already initialized the backend; this function no longer does so
+/// itself, so calling it before any context exists will hit the C++ side uninitialized.
 fn execute_bb_msgpack_command(command: &[u8]) -> (bool, Vec<u8>) {
     unsafe {
         let mut out_ptr: *mut u8 = ptr::null_mut();
         let mut out_len: usize = 0;
 
-        bindgen::bbapi_set_verbose_logging(true);
-        // Definitely don't do this every time. TODO - load CRS once.
-        if !bindgen::bbapi_init(null()) {
-            panic!("Failed to initialize bbapi");
-        }
         // Call the C++ bbapi function with all 4 required parameters
         let is_msgpack = bindgen::bbapi_non_chonk(
             command.as_ptr(), // input buffer
@@ -275,165 +393,412 @@ fn execute_bb_msgpack_command(command: &[u8]) -> (bool, Vec<u8>) {
     }
 }
 
-// High-level API functions using the new command-based approach
+/// A live bbapi session: owns the one-time backend initialization (in particular the
+/// structured-reference-string load), so a caller issuing many proves/verifies only pays that
+/// cost once instead of on every call.
+///
+/// The free functions in this module (`prove_ultra_honk`, `verify_ultra_honk`, etc.) are thin
+/// wrappers over a lazily-created global `BbContext`, for callers who don't need more than one
+/// session.
+pub struct BbContext {
+    _private: (),
+}
 
-/// Generate a proof using the bbapi command system
-pub fn prove_ultra_honk(
-    constraint_system_buf: &[u8],
-    witness_buf: &[u8],
-    vkey_buf: &[u8],
-) -> Result<CircuitProveResponse, BbApiError> {
-    let settings = ProofSystemSettings {
-        ipa_accumulation: false,
-        oracle_hash_type: "poseidon2".to_string(),
-        disable_zk: false,
-        optimized_solidity_verifier: false,
-    };
+impl BbContext {
+    /// Initializes the bbapi backend. Returns an error instead of panicking across the FFI
+    /// boundary if initialization fails.
+    pub fn new() -> Result<Self, BbApiError> {
+        unsafe {
+            if !bindgen::bbapi_init(null()) {
+                return Err(BbApiError::ApiError(
+                    "Failed to initialize bbapi".to_string(),
+                ));
+            }
+        }
+        Ok(Self { _private: () })
+    }
 
-    let command = CircuitProve {
-        circuit: CircuitInput {
-            name: "circuit".to_string(),
-            bytecode: constraint_system_buf.to_vec(),
-            verification_key: vkey_buf.to_vec(),
-        },
-        witness: witness_buf.to_vec(),
-        settings,
-    };
+    /// Toggles verbose C++-side logging. The flag is global to the backend: it is not reset when
+    /// this context is dropped.
+    pub fn set_verbose_logging(&self, verbose: bool) {
+        unsafe {
+            bindgen::bbapi_set_verbose_logging(verbose);
+        }
+    }
 
-    info!("Executing UltraHonk prover");
-    let response = bbapi_command::<CircuitProve, CircuitProveResponse>("CircuitProve", &command)?;
-    info!("UltraHonk prover returned successfully");
-    Ok(response)
-}
+    fn command<T, R>(&self, command_name: &str, command_data: &T) -> Result<R, BbApiError>
+    where
+        T: Serialize,
+        R: for<'de> Deserialize<'de>,
+    {
+        bbapi_command(command_name, command_data)
+    }
 
-/// Generate a proof using Keccak for EVM verification
-pub fn prove_ultra_keccak_honk(
-    constraint_system_buf: &[u8],
-    witness_buf: &[u8],
-    vkey_buf: &[u8],
-) -> Result<CircuitProveResponse, BbApiError> {
-    let settings = ProofSystemSettings {
-        ipa_accumulation: false,
-        oracle_hash_type: "keccak".to_string(),
-        disable_zk: true,
-        optimized_solidity_verifier: false,
-    };
+    /// Generate a proof using the bbapi command system
+    pub fn prove_ultra_honk(
+        &self,
+        constraint_system_buf: &[u8],
+        witness_buf: &[u8],
+        vkey_buf: &[u8],
+    ) -> Result<CircuitProveResponse, BbApiError> {
+        let settings = ProofSystemSettings {
+            ipa_accumulation: false,
+            oracle_hash_type: "poseidon2".to_string(),
+            disable_zk: false,
+            optimized_solidity_verifier: false,
+        };
+
+        let command = CircuitProve {
+            circuit: CircuitInput {
+                name: "circuit".to_string(),
+                bytecode: constraint_system_buf.to_vec(),
+                verification_key: vkey_buf.to_vec(),
+            },
+            witness: witness_buf.to_vec(),
+            settings,
+        };
+
+        info!("Executing UltraHonk prover");
+        let mut response =
+            self.command::<CircuitProve, CircuitProveResponse>("CircuitProve", &command)?;
+        response.settings = command.settings;
+        info!("UltraHonk prover returned successfully");
+        Ok(response)
+    }
 
-    let command = CircuitProve {
-        circuit: CircuitInput {
-            name: "circuit".to_string(),
-            bytecode: constraint_system_buf.to_vec(),
-            verification_key: vkey_buf.to_vec(),
-        },
-        witness: witness_buf.to_vec(),
-        settings,
-    };
+    /// Generate a proof using Keccak for EVM verification
+    pub fn prove_ultra_keccak_honk(
+        &self,
+        constraint_system_buf: &[u8],
+        witness_buf: &[u8],
+        vkey_buf: &[u8],
+    ) -> Result<CircuitProveResponse, BbApiError> {
+        let settings = ProofSystemSettings {
+            ipa_accumulation: false,
+            oracle_hash_type: "keccak".to_string(),
+            disable_zk: true,
+            optimized_solidity_verifier: false,
+        };
+
+        let command = CircuitProve {
+            circuit: CircuitInput {
+                name: "circuit".to_string(),
+                bytecode: constraint_system_buf.to_vec(),
+                verification_key: vkey_buf.to_vec(),
+            },
+            witness: witness_buf.to_vec(),
+            settings,
+        };
+
+        info!("Executing Barretenberg UltraHonk-NonZK prover (Keccak)");
+        let mut response =
+            self.command::<CircuitProve, CircuitProveResponse>("CircuitProve", &command)?;
+        response.settings = command.settings;
+        info!("UltraHonk-NonZK prover (Keccak) completed successfully");
+        Ok(response)
+    }
 
-    info!("Executing Barretenberg UltraHonk-NonZK prover (Keccak)");
-    let response = bbapi_command::<CircuitProve, CircuitProveResponse>("CircuitProve", &command)?;
-    info!("UltraHonk-NonZK prover (Keccak) completed successfully");
-    Ok(response)
-}
+    /// Generate a proof using Keccak with ZK enabled
+    pub fn prove_ultra_keccak_zk_honk(
+        &self,
+        constraint_system_buf: &[u8],
+        witness_buf: &[u8],
+        vkey_buf: &[u8],
+    ) -> Result<CircuitProveResponse, BbApiError> {
+        let settings = ProofSystemSettings {
+            ipa_accumulation: false,
+            oracle_hash_type: "keccak".to_string(),
+            disable_zk: false,
+            optimized_solidity_verifier: false,
+        };
+
+        let command = CircuitProve {
+            circuit: CircuitInput {
+                name: "circuit".to_string(),
+                bytecode: constraint_system_buf.to_vec(),
+                verification_key: vkey_buf.to_vec(),
+            },
+            witness: witness_buf.to_vec(),
+            settings,
+        };
+
+        info!("Executing Barretenberg UltraHonk-ZK prover (Keccak)");
+        let mut response =
+            self.command::<CircuitProve, CircuitProveResponse>("CircuitProve", &command)?;
+        response.settings = command.settings;
+        info!("UltraHonk-ZK prover (Keccak) completed successfully");
+        Ok(response)
+    }
 
-/// Generate a proof using Keccak with ZK enabled
-pub fn prove_ultra_keccak_zk_honk(
-    constraint_system_buf: &[u8],
-    witness_buf: &[u8],
-    vkey_buf: &[u8],
-) -> Result<CircuitProveResponse, BbApiError> {
-    let settings = ProofSystemSettings {
-        ipa_accumulation: false,
-        oracle_hash_type: "keccak".to_string(),
-        disable_zk: false,
-        optimized_solidity_verifier: false,
-    };
+    /// Proves many circuits against this context, reusing its one-time initialization instead of
+    /// paying the backend's setup cost (notably the CRS load) once per job. One job failing is
+    /// reported in its own slot rather than aborting the rest of the batch.
+    ///
+    /// # Safety
+    ///
+    /// With the `parallel` feature enabled, jobs are fanned out across a Rayon thread pool, so
+    /// the underlying C++ backend is invoked concurrently from multiple threads; this relies on
+    /// the same thread-safety assumption documented on [`execute_bb_msgpack_command`], which is
+    /// unverified. Without the feature, jobs run sequentially in a loop that still reuses this
+    /// context.
+    pub fn prove_ultra_honk_batch(
+        &self,
+        jobs: &[(Vec<u8>, Vec<u8>, Vec<u8>)],
+    ) -> Vec<Result<CircuitProveResponse, BbApiError>> {
+        #[cfg(feature = "parallel")]
+        {
+            jobs.par_iter()
+                .map(|(constraint_system_buf, witness_buf, vkey_buf)| {
+                    self.prove_ultra_honk(constraint_system_buf, witness_buf, vkey_buf)
+                })
+                .collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            jobs.iter()
+                .map(|(constraint_system_buf, witness_buf, vkey_buf)| {
+                    self.prove_ultra_honk(constraint_system_buf, witness_buf, vkey_buf)
+                })
+                .collect()
+        }
+    }
 
-    let command = CircuitProve {
-        circuit: CircuitInput {
-            name: "circuit".to_string(),
-            bytecode: constraint_system_buf.to_vec(),
-            verification_key: vkey_buf.to_vec(),
-        },
-        witness: witness_buf.to_vec(),
-        settings,
-    };
+    /// Folds several UltraHonk proofs into a single recursive proof, mirroring the
+    /// chunk-proofs-into-one-outer-proof pipeline other recursive provers use. The outer proof's
+    /// public inputs are the concatenated/merged public inputs of the children.
+    ///
+    /// All child proofs must have been produced under the same `oracle_hash_type` and
+    /// `disable_zk` settings, since the recursive verifier circuit is fixed to one transcript
+    /// hash; a mismatch is rejected with [`BbApiError::ApiError`] rather than silently picking
+    /// one child's settings.
+    pub fn aggregate_ultra_honk(
+        &self,
+        proofs: &[CircuitProveResponse],
+    ) -> Result<CircuitProveResponse, BbApiError> {
+        let first = proofs.first().ok_or_else(|| {
+            BbApiError::ApiError("Cannot aggregate an empty set of proofs".to_string())
+        })?;
 
-    info!("Executing Barretenberg UltraHonk-ZK prover (Keccak)");
-    let response = bbapi_command::<CircuitProve, CircuitProveResponse>("CircuitProve", &command)?;
-    info!("UltraHonk-ZK prover (Keccak) completed successfully");
-    Ok(response)
-}
+        for proof in &proofs[1..] {
+            if proof.settings.oracle_hash_type != first.settings.oracle_hash_type
+                || proof.settings.disable_zk != first.settings.disable_zk
+            {
+                return Err(BbApiError::ApiError(
+                    "Cannot aggregate proofs produced with mismatched oracle_hash_type or disable_zk settings"
+                        .to_string(),
+                ));
+            }
+        }
 
-/// Compute verification key
-pub fn get_ultra_honk_verification_key(
-    constraint_system_buf: &[u8],
-) -> Result<CircuitComputeVkResponse, BbApiError> {
-    let settings = ProofSystemSettings {
-        ipa_accumulation: false,
-        oracle_hash_type: "poseidon2".to_string(),
-        disable_zk: false,
-        optimized_solidity_verifier: false,
-    };
+        let instances = proofs
+            .iter()
+            .map(|proof| VerificationInstance {
+                verification_key: proof.vk.bytes.clone(),
+                public_inputs: proof.public_inputs.clone(),
+                proof: proof.proof.clone(),
+            })
+            .collect();
+
+        let settings = ProofSystemSettings {
+            ipa_accumulation: true,
+            oracle_hash_type: first.settings.oracle_hash_type.clone(),
+            disable_zk: first.settings.disable_zk,
+            optimized_solidity_verifier: false,
+        };
 
-    let command = CircuitComputeVk {
-        circuit: CircuitInputNoVK {
-            name: "circuit".to_string(),
-            bytecode: constraint_system_buf.to_vec(),
-        },
-        settings,
-    };
+        let command = CircuitAggregate {
+            instances,
+            settings: settings.clone(),
+        };
 
-    let response =
-        bbapi_command::<CircuitComputeVk, CircuitComputeVkResponse>("CircuitComputeVk", &command)?;
-    Ok(response)
-}
+        info!(
+            "Aggregating {} UltraHonk proofs into one recursive proof",
+            proofs.len()
+        );
+        let mut response = self
+            .command::<CircuitAggregate, CircuitProveResponse>("CircuitAggregate", &command)?;
+        response.settings = settings;
+        info!("Proof aggregation completed successfully");
+        Ok(response)
+    }
 
-/// Compute verification key for Keccak
-pub fn get_ultra_honk_keccak_verification_key(
-    constraint_system_buf: &[u8],
-) -> Result<Vec<u8>, BbApiError> {
-    let settings = ProofSystemSettings {
-        ipa_accumulation: false,
-        oracle_hash_type: "keccak".to_string(),
-        disable_zk: true,
-        optimized_solidity_verifier: false,
-    };
+    /// Compute verification key
+    pub fn get_ultra_honk_verification_key(
+        &self,
+        constraint_system_buf: &[u8],
+    ) -> Result<CircuitComputeVkResponse, BbApiError> {
+        let settings = ProofSystemSettings {
+            ipa_accumulation: false,
+            oracle_hash_type: "poseidon2".to_string(),
+            disable_zk: false,
+            optimized_solidity_verifier: false,
+        };
 
-    let command = CircuitComputeVk {
-        circuit: CircuitInputNoVK {
-            name: "circuit".to_string(),
-            bytecode: constraint_system_buf.to_vec(),
-        },
-        settings,
-    };
+        let command = CircuitComputeVk {
+            circuit: CircuitInputNoVK {
+                name: "circuit".to_string(),
+                bytecode: constraint_system_buf.to_vec(),
+            },
+            settings,
+        };
+
+        self.command::<CircuitComputeVk, CircuitComputeVkResponse>("CircuitComputeVk", &command)
+    }
+
+    /// Compute verification key for Keccak
+    pub fn get_ultra_honk_keccak_verification_key(
+        &self,
+        constraint_system_buf: &[u8],
+    ) -> Result<Vec<u8>, BbApiError> {
+        let settings = ProofSystemSettings {
+            ipa_accumulation: false,
+            oracle_hash_type: "keccak".to_string(),
+            disable_zk: true,
+            optimized_solidity_verifier: false,
+        };
+
+        let command = CircuitComputeVk {
+            circuit: CircuitInputNoVK {
+                name: "circuit".to_string(),
+                bytecode: constraint_system_buf.to_vec(),
+            },
+            settings,
+        };
+
+        let response = self
+            .command::<CircuitComputeVk, CircuitComputeVkResponse>("CircuitComputeVk", &command)?;
+        Ok(response.bytes)
+    }
+
+    /// Compute verification key for Keccak with ZK
+    pub fn get_ultra_honk_keccak_zk_verification_key(
+        &self,
+        constraint_system_buf: &[u8],
+    ) -> Result<Vec<u8>, BbApiError> {
+        let settings = ProofSystemSettings {
+            ipa_accumulation: false,
+            oracle_hash_type: "keccak".to_string(),
+            disable_zk: false,
+            optimized_solidity_verifier: false,
+        };
+
+        let command = CircuitComputeVk {
+            circuit: CircuitInputNoVK {
+                name: "circuit".to_string(),
+                bytecode: constraint_system_buf.to_vec(),
+            },
+            settings,
+        };
+
+        let response = self
+            .command::<CircuitComputeVk, CircuitComputeVkResponse>("CircuitComputeVk", &command)?;
+        Ok(response.bytes)
+    }
+
+    /// Emits the Solidity source for a verifier contract matching a keccak verification key, so a
+    /// caller that proved with [`BbContext::prove_ultra_keccak_honk`] can deploy a contract that
+    /// verifies those proofs on-chain. `optimized_solidity_verifier: true` toggles the
+    /// gas-optimized variant of the contract.
+    pub fn get_ultra_keccak_honk_solidity_verifier(
+        &self,
+        constraint_system_buf: &[u8],
+        optimized_solidity_verifier: bool,
+    ) -> Result<CircuitWriteSolidityVerifierResponse, BbApiError> {
+        let settings = ProofSystemSettings {
+            ipa_accumulation: false,
+            oracle_hash_type: "keccak".to_string(),
+            disable_zk: true,
+            optimized_solidity_verifier,
+        };
+
+        let command = CircuitWriteSolidityVerifier {
+            circuit: CircuitInputNoVK {
+                name: "circuit".to_string(),
+                bytecode: constraint_system_buf.to_vec(),
+            },
+            settings,
+        };
+
+        info!("Generating UltraKeccakHonk Solidity verifier contract");
+        self.command::<CircuitWriteSolidityVerifier, CircuitWriteSolidityVerifierResponse>(
+            "CircuitWriteSolidityVerifier",
+            &command,
+        )
+    }
+
+    /// Verify a proof
+    pub fn verify_ultra_honk(&self, proof: CircuitProveResponse) -> Result<bool, BbApiError> {
+        let command = to_verify(proof, false, "poseidon2", false)?;
+        info!("Executing UltraHonk verifier");
+        let response =
+            self.command::<CircuitVerify, CircuitVerifyResponse>("CircuitVerify", &command)?;
+        info!(
+            "UltraHonk verifier returned with result: {}",
+            response.verified
+        );
+        Ok(response.verified)
+    }
+
+    /// Verify a Keccak proof
+    pub fn verify_ultra_keccak_honk(
+        &self,
+        proof: CircuitProveResponse,
+    ) -> Result<bool, BbApiError> {
+        let command = to_verify(proof, false, "keccak", true)?;
+        info!("Executing Keccak verifier");
+        let response =
+            self.command::<CircuitVerify, CircuitVerifyResponse>("CircuitVerify", &command)?;
+        info!(
+            "Keccak verifier returned with result: {}",
+            response.verified
+        );
+        Ok(response.verified)
+    }
 
-    let response =
-        bbapi_command::<CircuitComputeVk, CircuitComputeVkResponse>("CircuitComputeVk", &command)?;
-    Ok(response.bytes)
+    /// Verify a Keccak ZK proof
+    pub fn verify_ultra_keccak_zk_honk(
+        &self,
+        proof: CircuitProveResponse,
+    ) -> Result<bool, BbApiError> {
+        let command = to_verify(proof, false, "keccak", false)?;
+
+        info!("Executing UltraKeccakZK verifier");
+        let response =
+            self.command::<CircuitVerify, CircuitVerifyResponse>("CircuitVerify", &command)?;
+        info!(
+            "UltraKeccakZK verifier returned with result: {}",
+            response.verified
+        );
+        Ok(response.verified)
+    }
 }
 
-/// Compute verification key for Keccak with ZK
-pub fn get_ultra_honk_keccak_zk_verification_key(
-    constraint_system_buf: &[u8],
-) -> Result<Vec<u8>, BbApiError> {
-    let settings = ProofSystemSettings {
-        ipa_accumulation: false,
-        oracle_hash_type: "keccak".to_string(),
-        disable_zk: false,
-        optimized_solidity_verifier: false,
-    };
+/// The global, lazily-initialized [`BbContext`] the free functions in this module route through.
+///
+/// Caches initialization failure (as a message, since [`BbApiError`] isn't `Clone`) rather than
+/// retrying `bbapi_init` on every call once it has failed once.
+fn global_context() -> Result<&'static BbContext, BbApiError> {
+    static CONTEXT: OnceLock<Result<BbContext, String>> = OnceLock::new();
+    match CONTEXT.get_or_init(|| BbContext::new().map_err(|e| e.to_string())) {
+        Ok(context) => Ok(context),
+        Err(message) => Err(BbApiError::ApiError(message.clone())),
+    }
+}
 
-    let command = CircuitComputeVk {
-        circuit: CircuitInputNoVK {
-            name: "circuit".to_string(),
-            bytecode: constraint_system_buf.to_vec(),
-        },
-        settings,
-    };
+/// A single child proof's verification instance, as folded into a recursive proof by
+/// [`BbContext::aggregate_ultra_honk`].
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VerificationInstance {
+    #[serde(with = "serde_bytes")]
+    pub verification_key: Vec<u8>,
+    pub public_inputs: Vec<Uint256>,
+    pub proof: Vec<Uint256>,
+}
 
-    let response =
-        bbapi_command::<CircuitComputeVk, CircuitComputeVkResponse>("CircuitComputeVk", &command)?;
-    Ok(response.bytes)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CircuitAggregate {
+    pub instances: Vec<VerificationInstance>,
+    pub settings: ProofSystemSettings,
 }
 
 fn to_verify(
@@ -463,42 +828,102 @@ fn to_verify(
     })
 }
 
+// High-level free functions, kept for backwards compatibility: thin wrappers over a lazily-created
+// global `BbContext`. Callers managing many proves/verifies in one session should construct and
+// reuse their own `BbContext` instead.
+
+/// Generate a proof using the bbapi command system
+pub fn prove_ultra_honk(
+    constraint_system_buf: &[u8],
+    witness_buf: &[u8],
+    vkey_buf: &[u8],
+) -> Result<CircuitProveResponse, BbApiError> {
+    global_context()?.prove_ultra_honk(constraint_system_buf, witness_buf, vkey_buf)
+}
+
+/// Generate a proof using Keccak for EVM verification
+pub fn prove_ultra_keccak_honk(
+    constraint_system_buf: &[u8],
+    witness_buf: &[u8],
+    vkey_buf: &[u8],
+) -> Result<CircuitProveResponse, BbApiError> {
+    global_context()?.prove_ultra_keccak_honk(constraint_system_buf, witness_buf, vkey_buf)
+}
+
+/// Generate a proof using Keccak with ZK enabled
+pub fn prove_ultra_keccak_zk_honk(
+    constraint_system_buf: &[u8],
+    witness_buf: &[u8],
+    vkey_buf: &[u8],
+) -> Result<CircuitProveResponse, BbApiError> {
+    global_context()?.prove_ultra_keccak_zk_honk(constraint_system_buf, witness_buf, vkey_buf)
+}
+
+/// Proves many circuits against the global context. See [`BbContext::prove_ultra_honk_batch`].
+pub fn prove_ultra_honk_batch(
+    jobs: &[(Vec<u8>, Vec<u8>, Vec<u8>)],
+) -> Vec<Result<CircuitProveResponse, BbApiError>> {
+    match global_context() {
+        Ok(context) => context.prove_ultra_honk_batch(jobs),
+        Err(e) => {
+            let message = e.to_string();
+            jobs.iter()
+                .map(|_| Err(BbApiError::ApiError(message.clone())))
+                .collect()
+        }
+    }
+}
+
+/// Folds several UltraHonk proofs into a single recursive proof. See
+/// [`BbContext::aggregate_ultra_honk`].
+pub fn aggregate_ultra_honk(
+    proofs: &[CircuitProveResponse],
+) -> Result<CircuitProveResponse, BbApiError> {
+    global_context()?.aggregate_ultra_honk(proofs)
+}
+
+/// Compute verification key
+pub fn get_ultra_honk_verification_key(
+    constraint_system_buf: &[u8],
+) -> Result<CircuitComputeVkResponse, BbApiError> {
+    global_context()?.get_ultra_honk_verification_key(constraint_system_buf)
+}
+
+/// Compute verification key for Keccak
+pub fn get_ultra_honk_keccak_verification_key(
+    constraint_system_buf: &[u8],
+) -> Result<Vec<u8>, BbApiError> {
+    global_context()?.get_ultra_honk_keccak_verification_key(constraint_system_buf)
+}
+
+/// Compute verification key for Keccak with ZK
+pub fn get_ultra_honk_keccak_zk_verification_key(
+    constraint_system_buf: &[u8],
+) -> Result<Vec<u8>, BbApiError> {
+    global_context()?.get_ultra_honk_keccak_zk_verification_key(constraint_system_buf)
+}
+
+/// Generate the Solidity source for an UltraKeccakHonk verifier contract. See
+/// [`BbContext::get_ultra_keccak_honk_solidity_verifier`].
+pub fn get_ultra_keccak_honk_solidity_verifier(
+    constraint_system_buf: &[u8],
+    optimized_solidity_verifier: bool,
+) -> Result<CircuitWriteSolidityVerifierResponse, BbApiError> {
+    global_context()?
+        .get_ultra_keccak_honk_solidity_verifier(constraint_system_buf, optimized_solidity_verifier)
+}
+
 /// Verify a proof
 pub fn verify_ultra_honk(proof: CircuitProveResponse) -> Result<bool, BbApiError> {
-    let command = to_verify(proof, false, "poseidon2", false)?;
-    info!("Executing UltraHonk verifier");
-    let response =
-        bbapi_command::<CircuitVerify, CircuitVerifyResponse>("CircuitVerify", &command)?;
-    info!(
-        "UltraHonk verifier returned with result: {}",
-        response.verified
-    );
-    Ok(response.verified)
+    global_context()?.verify_ultra_honk(proof)
 }
 
 /// Verify a Keccak proof
 pub fn verify_ultra_keccak_honk(proof: CircuitProveResponse) -> Result<bool, BbApiError> {
-    let command = to_verify(proof, false, "keccak", true)?;
-    info!("Executing Keccak verifier");
-    let response =
-        bbapi_command::<CircuitVerify, CircuitVerifyResponse>("CircuitVerify", &command)?;
-    info!(
-        "Keccak verifier returned with result: {}",
-        response.verified
-    );
-    Ok(response.verified)
+    global_context()?.verify_ultra_keccak_honk(proof)
 }
 
 /// Verify a Keccak ZK proof
 pub fn verify_ultra_keccak_zk_honk(proof: CircuitProveResponse) -> Result<bool, BbApiError> {
-    let command = to_verify(proof, false, "keccak", false)?;
-
-    info!("Executing UltraKeccakZK verifier");
-    let response =
-        bbapi_command::<CircuitVerify, CircuitVerifyResponse>("CircuitVerify", &command)?;
-    info!(
-        "UltraKeccakZK verifier returned with result: {}",
-        response.verified
-    );
-    Ok(response.verified)
+    global_context()?.verify_ultra_keccak_zk_honk(proof)
 }
@@ -0,0 +1,790 @@
+// Safe, malleability-resistant ECDSA API over the raw `ecdsa__*` / `ecdsa_r_*` bindings.
+//
+// The functions in this module wrap the unsafe bindgen bindings below and normalize every
+// produced signature to low-S form, mirroring the approach rust-bitcoin's `ecdsa` module takes
+// to prevent the classic (r, s) / (r, n-s) malleability footgun.
+//
+// See the sibling [`crate::barretenberg_api::schnorr`] module for Barretenberg's other signing
+// scheme: a 32-byte-secret-key/arbitrary-message/64-byte-signature API in the same shape as this
+// one, over Grumpkin rather than secp256k1/secp256r1.
+
+use crate::barretenberg_api::bindgen;
+use crate::barretenberg_api::utils::SerializeBuffer;
+use hmac::{Hmac, Mac};
+use num_bigint::BigUint;
+use sha2::Sha256;
+use std::fmt;
+
+/// The order of the secp256k1 group.
+const SECP256K1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// The order of the secp256r1 (P-256) group.
+const SECP256R1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xbc, 0xe6, 0xfa, 0xad, 0xa7, 0x17, 0x9e, 0x84, 0xf3, 0xb9, 0xca, 0xc2, 0xfc, 0x63, 0x25, 0x51,
+];
+
+/// Identifies which curve family a [`Signature`] belongs to, since secp256k1 and secp256r1 use
+/// different group orders when normalizing `s`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Curve {
+    Secp256k1,
+    Secp256r1,
+}
+
+/// The secp256k1 base field prime.
+const SECP256K1_FIELD_PRIME: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xfc, 0x2f,
+];
+
+/// The secp256r1 (P-256) base field prime.
+const SECP256R1_FIELD_PRIME: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+];
+
+/// The secp256r1 (P-256) curve's `b` coefficient (`y^2 = x^3 - 3x + b`).
+const SECP256R1_B: [u8; 32] = [
+    0x5a, 0xc6, 0x35, 0xd8, 0xaa, 0x3a, 0x93, 0xe7, 0xb3, 0xeb, 0xbd, 0x55, 0x76, 0x98, 0x86, 0xbc,
+    0x65, 0x1d, 0x06, 0xb0, 0xcc, 0x53, 0xb0, 0xf6, 0x3b, 0xce, 0x3c, 0x3e, 0x27, 0xd2, 0x60, 0x4b,
+];
+
+impl Curve {
+    fn order(self) -> BigUint {
+        match self {
+            Curve::Secp256k1 => BigUint::from_bytes_be(&SECP256K1_ORDER),
+            Curve::Secp256r1 => BigUint::from_bytes_be(&SECP256R1_ORDER),
+        }
+    }
+
+    /// The base field prime `p` of this curve's coordinate field (distinct from [`Self::order`],
+    /// the order of the curve's point group).
+    fn field_prime(self) -> BigUint {
+        match self {
+            Curve::Secp256k1 => BigUint::from_bytes_be(&SECP256K1_FIELD_PRIME),
+            Curve::Secp256r1 => BigUint::from_bytes_be(&SECP256R1_FIELD_PRIME),
+        }
+    }
+
+    /// Evaluates the right-hand side of this curve's short Weierstrass equation, `x^3 + a*x + b`,
+    /// reduced modulo [`Self::field_prime`].
+    fn weierstrass_rhs(self, x: &BigUint, p: &BigUint) -> BigUint {
+        let x3 = x.modpow(&BigUint::from(3u32), p);
+        match self {
+            Curve::Secp256k1 => (x3 + 7u32) % p,
+            Curve::Secp256r1 => {
+                let b = BigUint::from_bytes_be(&SECP256R1_B);
+                // a = -3, so a*x mod p = p - ((3*x) mod p).
+                let three_x = (BigUint::from(3u32) * x) % p;
+                let a_x = p - three_x;
+                (x3 + a_x + b) % p
+            }
+        }
+    }
+}
+
+/// Computes a square root of `a` modulo the prime `p`, where `p ≡ 3 (mod 4)` -- true of both
+/// secp256k1's and secp256r1's field primes -- via the direct `a^((p+1)/4) mod p` formula. Returns
+/// `None` if `a` is not a quadratic residue mod `p`.
+fn mod_sqrt(a: &BigUint, p: &BigUint) -> Option<BigUint> {
+    let exponent = (p + 1u32) >> 2u32;
+    let candidate = a.modpow(&exponent, p);
+    if (&candidate * &candidate) % p == a % p {
+        Some(candidate)
+    } else {
+        None
+    }
+}
+
+/// Derives the RFC 6979 candidate nonce `k` via HMAC-DRBG (SHA-256) for `private_key` and the
+/// 32-byte message hash `h1`, deterministic in its inputs alone.
+///
+/// This is the nonce-generation half of RFC 6979; it does not, on its own, give us a deterministic
+/// `ecdsa__construct_signature_deterministic` binding. Computing a signature from this nonce needs
+/// `R = k * G` over the signing curve, and the only scalar-multiplication primitive this crate
+/// binds for secp256k1 ([`crate::barretenberg_api::untested::secp256k1::ecc_secp256k1__mul`]) takes
+/// its scalar as the BN254-scalar-field-tied [`crate::barretenberg_api::models::Fr`], which rejects
+/// (or silently reduces) secp256k1 scalars outside BN254's smaller modulus -- not a faithful
+/// representation of a secp256k1 nonce. A real `ecdsa__construct_signature_deterministic` needs a
+/// C++ entry point that accepts an explicit nonce; this FFI surface only exposes the randomized
+/// `ecdsa__construct_signature_`/`ecdsa_r_construct_signature_` pair, so that binding isn't
+/// implementable here. This function is still useful on its own wherever a caller already has a
+/// correctly-moduli'd way to turn `k` into `R = k * G` (e.g. an external secp256k1 implementation).
+pub fn rfc6979_nonce(private_key: &[u8; 32], h1: &[u8; 32], curve: Curve) -> [u8; 32] {
+    let n = curve.order();
+    let one = BigUint::from(1u32);
+
+    let mut v = [0x01u8; 32];
+    let mut k = [0x00u8; 32];
+
+    k = hmac_sha256(&k, &[&v, &[0x00], private_key, h1]);
+    v = hmac_sha256(&k, &[&v]);
+    k = hmac_sha256(&k, &[&v, &[0x01], private_key, h1]);
+    v = hmac_sha256(&k, &[&v]);
+
+    loop {
+        v = hmac_sha256(&k, &[&v]);
+        let candidate = BigUint::from_bytes_be(&v);
+        if candidate >= one && candidate < n {
+            return v;
+        }
+        k = hmac_sha256(&k, &[&v, &[0x00]]);
+        v = hmac_sha256(&k, &[&v]);
+    }
+}
+
+fn hmac_sha256(key: &[u8], chunks: &[&[u8]]) -> [u8; 32] {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(key).expect("HMAC-SHA256 accepts any key length");
+    for chunk in chunks {
+        mac.update(chunk);
+    }
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&mac.finalize().into_bytes());
+    out
+}
+
+/// Errors produced while constructing, parsing, or verifying ECDSA signatures.
+#[derive(Debug, thiserror::Error)]
+pub enum EcdsaError {
+    #[error("signature verification failed")]
+    InvalidSignature,
+    #[error("invalid signature encoding: {0}")]
+    InvalidEncoding(String),
+    #[error("context does not have the {0} capability")]
+    CapabilityDenied(&'static str),
+}
+
+/// A safe, curve-tagged ECDSA signature in `(r, s, recovery_id)` form.
+///
+/// Signatures are always constructed in low-S (canonical, non-malleable) form: see
+/// [`Signature::normalize_s`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature {
+    pub r: [u8; 32],
+    pub s: [u8; 32],
+    pub recovery_id: u8,
+    curve: Curve,
+}
+
+impl Signature {
+    fn new(r: [u8; 32], s: [u8; 32], recovery_id: u8, curve: Curve) -> Self {
+        let mut sig = Self { r, s, recovery_id, curve };
+        sig.normalize_s();
+        sig
+    }
+
+    /// Returns `true` if `s` is already in canonical (low-S) form for this signature's curve.
+    pub fn is_normalized(&self) -> bool {
+        let s = BigUint::from_bytes_be(&self.s);
+        let half_order = self.curve.order() >> 1u32;
+        s <= half_order
+    }
+
+    /// Normalizes `s` to the low-S form in place, flipping the parity bit of the recovery id
+    /// so the signature continues to verify and recover the same public key. Returns `true` if
+    /// a flip was performed.
+    pub fn normalize_s(&mut self) -> bool {
+        if self.is_normalized() {
+            return false;
+        }
+        let n = self.curve.order();
+        let s = BigUint::from_bytes_be(&self.s);
+        let new_s = n - s;
+        self.s = biguint_to_32_bytes(&new_s);
+        self.recovery_id ^= 1;
+        true
+    }
+
+    /// Serializes the signature to the 64-byte compact `r || s` form.
+    pub fn to_compact(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        out[..32].copy_from_slice(&self.r);
+        out[32..].copy_from_slice(&self.s);
+        out
+    }
+
+    /// Parses a signature from the 64-byte compact `r || s` form. The recovery id is not
+    /// encoded in this form and must be supplied separately.
+    pub fn from_compact(bytes: &[u8; 64], recovery_id: u8, curve: Curve) -> Self {
+        let mut r = [0u8; 32];
+        let mut s = [0u8; 32];
+        r.copy_from_slice(&bytes[..32]);
+        s.copy_from_slice(&bytes[32..]);
+        Self::new(r, s, recovery_id, curve)
+    }
+
+    /// Serializes the signature to the 65-byte `r || s || v` form used by Ethereum, where `v`
+    /// is `recovery_id + 27`.
+    pub fn to_eth_bytes(&self) -> [u8; 65] {
+        let mut out = [0u8; 65];
+        out[..32].copy_from_slice(&self.r);
+        out[32..64].copy_from_slice(&self.s);
+        out[64] = self.recovery_id + 27;
+        out
+    }
+
+    /// Parses a signature from the 65-byte `r || s || v` form, accepting `v` in `{0,1,27,28}`.
+    pub fn from_eth_bytes(bytes: &[u8; 65], curve: Curve) -> Result<Self, EcdsaError> {
+        let mut r = [0u8; 32];
+        let mut s = [0u8; 32];
+        r.copy_from_slice(&bytes[..32]);
+        s.copy_from_slice(&bytes[32..64]);
+        let recovery_id = match bytes[64] {
+            0 | 1 => bytes[64],
+            27 | 28 => bytes[64] - 27,
+            other => {
+                return Err(EcdsaError::InvalidEncoding(format!(
+                    "recovery byte must be one of {{0,1,27,28}}, got {other}"
+                )))
+            }
+        };
+        Ok(Self::new(r, s, recovery_id, curve))
+    }
+
+    /// Serializes the signature as DER, the ASN.1 `SEQUENCE { r INTEGER, s INTEGER }` encoding.
+    pub fn to_der(&self) -> Vec<u8> {
+        let mut body = Vec::new();
+        encode_der_integer(&self.r, &mut body);
+        encode_der_integer(&self.s, &mut body);
+
+        let mut out = vec![0x30];
+        encode_der_length(body.len(), &mut out);
+        out.extend_from_slice(&body);
+        out
+    }
+
+    /// Parses a DER-encoded `SEQUENCE { r INTEGER, s INTEGER }`. The recovery id is not
+    /// recoverable from DER and must be supplied separately.
+    pub fn from_der(der: &[u8], recovery_id: u8, curve: Curve) -> Result<Self, EcdsaError> {
+        if der.len() < 2 || der[0] != 0x30 {
+            return Err(EcdsaError::InvalidEncoding("not a DER SEQUENCE".to_string()));
+        }
+        let (seq_len, mut offset) = decode_der_length(der, 1)?;
+        if der.len() != offset + seq_len {
+            return Err(EcdsaError::InvalidEncoding("trailing DER bytes".to_string()));
+        }
+        let (r, new_offset) = decode_der_integer(der, offset)?;
+        offset = new_offset;
+        let (s, offset) = decode_der_integer(der, offset)?;
+        if offset != der.len() {
+            return Err(EcdsaError::InvalidEncoding("trailing DER bytes".to_string()));
+        }
+        Ok(Self::new(r, s, recovery_id, curve))
+    }
+}
+
+fn biguint_to_32_bytes(value: &BigUint) -> [u8; 32] {
+    let bytes = value.to_bytes_be();
+    let mut out = [0u8; 32];
+    out[32 - bytes.len()..].copy_from_slice(&bytes);
+    out
+}
+
+fn encode_der_length(len: usize, out: &mut Vec<u8>) {
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let len_bytes = len.to_be_bytes();
+        let first_nonzero = len_bytes.iter().position(|&b| b != 0).unwrap_or(len_bytes.len() - 1);
+        let significant = &len_bytes[first_nonzero..];
+        out.push(0x80 | significant.len() as u8);
+        out.extend_from_slice(significant);
+    }
+}
+
+fn decode_der_length(der: &[u8], offset: usize) -> Result<(usize, usize), EcdsaError> {
+    let first = *der
+        .get(offset)
+        .ok_or_else(|| EcdsaError::InvalidEncoding("truncated DER length".to_string()))?;
+    if first < 0x80 {
+        Ok((first as usize, offset + 1))
+    } else {
+        let num_bytes = (first & 0x7f) as usize;
+        let bytes = der
+            .get(offset + 1..offset + 1 + num_bytes)
+            .ok_or_else(|| EcdsaError::InvalidEncoding("truncated DER length".to_string()))?;
+        let mut len = 0usize;
+        for b in bytes {
+            len = (len << 8) | *b as usize;
+        }
+        Ok((len, offset + 1 + num_bytes))
+    }
+}
+
+fn encode_der_integer(value_be: &[u8; 32], out: &mut Vec<u8>) {
+    let mut bytes: &[u8] = value_be;
+    while bytes.len() > 1 && bytes[0] == 0 {
+        bytes = &bytes[1..];
+    }
+    let needs_leading_zero = bytes[0] & 0x80 != 0;
+
+    out.push(0x02);
+    let len = bytes.len() + if needs_leading_zero { 1 } else { 0 };
+    encode_der_length(len, out);
+    if needs_leading_zero {
+        out.push(0x00);
+    }
+    out.extend_from_slice(bytes);
+}
+
+fn decode_der_integer(der: &[u8], offset: usize) -> Result<([u8; 32], usize), EcdsaError> {
+    if der.get(offset) != Some(&0x02) {
+        return Err(EcdsaError::InvalidEncoding("expected DER INTEGER".to_string()));
+    }
+    let (len, offset) = decode_der_length(der, offset + 1)?;
+    let bytes = der
+        .get(offset..offset + len)
+        .ok_or_else(|| EcdsaError::InvalidEncoding("truncated DER integer".to_string()))?;
+    let trimmed = if bytes.len() > 1 && bytes[0] == 0 { &bytes[1..] } else { bytes };
+    if trimmed.len() > 32 {
+        return Err(EcdsaError::InvalidEncoding("DER integer too large".to_string()));
+    }
+    let mut out = [0u8; 32];
+    out[32 - trimmed.len()..].copy_from_slice(trimmed);
+    Ok((out, offset + len))
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(self.to_compact()))
+    }
+}
+
+/// Recovers the 20-byte Ethereum address that signed `hash`, mirroring the behavior of the
+/// `ecrecover` precompile at EVM address `0x01` (see OpenEthereum's `builtin.rs`).
+///
+/// The 65-byte `signature` is `r || s || v`. `v` must be `27`, `28`, `0`, or `1`, and `s` must
+/// not exceed the secp256k1 half-order (the precompile rejects high-S signatures rather than
+/// normalizing them).
+pub fn ecrecover(hash: &[u8; 32], signature: &[u8; 65]) -> Result<[u8; 20], EcdsaError> {
+    let mut r = [0u8; 32];
+    let mut s = [0u8; 32];
+    r.copy_from_slice(&signature[..32]);
+    s.copy_from_slice(&signature[32..64]);
+
+    let recovery_id = match signature[64] {
+        0 | 1 => signature[64],
+        27 | 28 => signature[64] - 27,
+        other => {
+            return Err(EcdsaError::InvalidEncoding(format!(
+                "v must be one of {{0,1,27,28}}, got {other}"
+            )))
+        }
+    };
+
+    let order = Curve::Secp256k1.order();
+    let r_value = BigUint::from_bytes_be(&r);
+    if r_value == BigUint::from(0u32) || r_value >= order {
+        return Err(EcdsaError::InvalidEncoding(
+            "r must be nonzero and below the secp256k1 order".to_string(),
+        ));
+    }
+
+    let half_order = order >> 1u32;
+    if BigUint::from_bytes_be(&s) > half_order {
+        return Err(EcdsaError::InvalidEncoding(
+            "s must not exceed the secp256k1 half-order".to_string(),
+        ));
+    }
+
+    let mut recovery_id_mut = recovery_id;
+    let public_key =
+        unsafe { ecdsa__recover_public_key_from_signature_(hash, &r, &s, &mut recovery_id_mut) };
+
+    let mut hasher = tiny_keccak::Keccak::v256();
+    let mut digest = [0u8; 32];
+    tiny_keccak::Hasher::update(&mut hasher, &public_key);
+    tiny_keccak::Hasher::finalize(hasher, &mut digest);
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&digest[12..]);
+    Ok(address)
+}
+
+/// Which operations an [`EcdsaContext`] is permitted to perform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+    Sign,
+    Verify,
+    All,
+}
+
+impl Capability {
+    fn can_sign(self) -> bool {
+        matches!(self, Capability::Sign | Capability::All)
+    }
+
+    fn can_verify(self) -> bool {
+        matches!(self, Capability::Verify | Capability::All)
+    }
+}
+
+/// A reusable, curve- and capability-scoped signing/verification handle.
+///
+/// Implementations like libsecp256k1 amortize a one-time, ~10ms precomputation-table build across
+/// many signing/verification calls by building it once into a context object, turning each
+/// subsequent call into microseconds of work. This crate's FFI surface doesn't expose an
+/// equivalent "build a context" entry point -- every `ecdsa__*`/`ecdsa_r_*` call does its own setup
+/// on the C++ side of the boundary -- so `EcdsaContext` doesn't cache a precomputation table
+/// itself; what it provides today is the capability-scoped API shape (sign-only / verify-only /
+/// both) the bare module functions don't have, so a batch-proving pipeline can hold one context
+/// per curve and be confident a verify-only handle can never be asked to sign. Since it only holds
+/// plain `Copy` data, it's `Send`/`Sync` for free and safe to share across threads.
+#[derive(Debug, Clone, Copy)]
+pub struct EcdsaContext {
+    curve: Curve,
+    capability: Capability,
+}
+
+impl EcdsaContext {
+    pub fn new(curve: Curve, capability: Capability) -> Self {
+        Self { curve, capability }
+    }
+
+    pub fn curve(&self) -> Curve {
+        self.curve
+    }
+
+    pub fn capability(&self) -> Capability {
+        self.capability
+    }
+
+    /// Derives the public key for `private_key`. Key derivation doesn't depend on the context's
+    /// capability, since it's neither signing nor verifying.
+    pub fn compute_public_key(&self, private_key: &[u8; 32]) -> [u8; 64] {
+        match self.curve {
+            Curve::Secp256k1 => secp256k1::compute_public_key(private_key),
+            Curve::Secp256r1 => secp256r1::compute_public_key(private_key),
+        }
+    }
+
+    /// Signs `message` with `private_key`, returning a low-S normalized [`Signature`]. Fails if
+    /// this context wasn't created with [`Capability::Sign`] or [`Capability::All`].
+    pub fn sign(&self, message: &[u8], private_key: &[u8; 32]) -> Result<Signature, EcdsaError> {
+        if !self.capability.can_sign() {
+            return Err(EcdsaError::CapabilityDenied("sign"));
+        }
+        Ok(match self.curve {
+            Curve::Secp256k1 => secp256k1::sign(message, private_key),
+            Curve::Secp256r1 => secp256r1::sign(message, private_key),
+        })
+    }
+
+    /// Verifies `signature` over `message` against `public_key`. Fails if this context wasn't
+    /// created with [`Capability::Verify`] or [`Capability::All`].
+    pub fn verify(
+        &self,
+        message: &[u8],
+        public_key: &[u8; 64],
+        signature: &Signature,
+    ) -> Result<bool, EcdsaError> {
+        if !self.capability.can_verify() {
+            return Err(EcdsaError::CapabilityDenied("verify"));
+        }
+        Ok(match self.curve {
+            Curve::Secp256k1 => secp256k1::verify(message, public_key, signature),
+            Curve::Secp256r1 => secp256r1::verify(message, public_key, signature),
+        })
+    }
+}
+
+/// Safe secp256k1 signing/verification/recovery built on the raw `ecdsa__*` bindings.
+pub mod secp256k1 {
+    use super::*;
+
+    /// Signs `message` with `private_key`, returning a low-S normalized [`Signature`].
+    pub fn sign(message: &[u8], private_key: &[u8; 32]) -> Signature {
+        let (r, s, recovery_id) = unsafe { ecdsa__construct_signature_(message, private_key) };
+        Signature::new(r, s, recovery_id, Curve::Secp256k1)
+    }
+
+    /// Verifies `signature` over `message` against `public_key`.
+    pub fn verify(message: &[u8], public_key: &[u8; 64], signature: &Signature) -> bool {
+        unsafe {
+            ecdsa__verify_signature_(message, public_key, &signature.r, &signature.s, &signature.recovery_id)
+        }
+    }
+
+    /// Recovers the 64-byte uncompressed public key that produced `signature` over `message`.
+    pub fn recover(message: &[u8], signature: &Signature) -> [u8; 64] {
+        let mut recovery_id = signature.recovery_id;
+        unsafe { ecdsa__recover_public_key_from_signature_(message, &signature.r, &signature.s, &mut recovery_id) }
+    }
+
+    /// Derives the 64-byte uncompressed public key for `private_key`.
+    pub fn compute_public_key(private_key: &[u8; 32]) -> [u8; 64] {
+        unsafe { ecdsa__compute_public_key(private_key) }
+    }
+}
+
+/// Safe secp256r1 signing/verification/recovery built on the raw `ecdsa_r_*` bindings.
+pub mod secp256r1 {
+    use super::*;
+
+    /// Signs `message` with `private_key`, returning a low-S normalized [`Signature`].
+    pub fn sign(message: &[u8], private_key: &[u8; 32]) -> Signature {
+        let (r, s, recovery_id) = unsafe { ecdsa_r_construct_signature_(message, private_key) };
+        Signature::new(r, s, recovery_id, Curve::Secp256r1)
+    }
+
+    /// Verifies `signature` over `message` against `public_key`.
+    pub fn verify(message: &[u8], public_key: &[u8; 64], signature: &Signature) -> bool {
+        unsafe {
+            ecdsa_r_verify_signature_(message, public_key, &signature.r, &signature.s, &signature.recovery_id)
+        }
+    }
+
+    /// Recovers the 64-byte uncompressed public key that produced `signature` over `message`.
+    pub fn recover(message: &[u8], signature: &Signature) -> [u8; 64] {
+        let mut recovery_id = signature.recovery_id;
+        unsafe { ecdsa_r_recover_public_key_from_signature_(message, &signature.r, &signature.s, &mut recovery_id) }
+    }
+
+    /// Derives the 64-byte uncompressed public key for `private_key`.
+    pub fn compute_public_key(private_key: &[u8; 32]) -> [u8; 64] {
+        unsafe { ecdsa_r_compute_public_key(private_key) }
+    }
+}
+
+// ECDSA secp256k1 curve functions
+
+pub unsafe fn ecdsa__compute_public_key(private_key: &[u8; 32]) -> [u8; 64] {
+    let mut public_key = [0; 64];
+    bindgen::ecdsa__compute_public_key(private_key.as_ptr(), public_key.as_mut_ptr());
+    public_key
+}
+
+pub unsafe fn ecdsa__construct_signature_(
+    message_buf: &[u8],
+    private_key: &[u8; 32],
+) -> ([u8; 32], [u8; 32], u8) {
+    let mut sig_r = [0; 32];
+    let mut sig_s = [0; 32];
+    let mut sig_v = 0u8;
+    bindgen::ecdsa__construct_signature_(
+        message_buf.to_buffer().as_slice().as_ptr(),
+        private_key.as_ptr(),
+        sig_r.as_mut_ptr(),
+        sig_s.as_mut_ptr(),
+        &mut sig_v,
+    );
+    (sig_r, sig_s, sig_v)
+}
+
+pub unsafe fn ecdsa__recover_public_key_from_signature_(
+    message_buf: &[u8],
+    sig_r: &[u8; 32],
+    sig_s: &[u8; 32],
+    sig_v: &mut u8,
+) -> [u8; 64] {
+    let mut output_pub_key = [0; 64];
+    bindgen::ecdsa__recover_public_key_from_signature_(
+        message_buf.to_buffer().as_slice().as_ptr(),
+        sig_r.as_ptr(),
+        sig_s.as_ptr(),
+        sig_v,
+        output_pub_key.as_mut_ptr(),
+    );
+    output_pub_key
+}
+
+pub unsafe fn ecdsa__verify_signature_(
+    message_buf: &[u8],
+    pub_key: &[u8; 64],
+    sig_r: &[u8; 32],
+    sig_s: &[u8; 32],
+    sig_v: &u8,
+) -> bool {
+    let mut result = false;
+    bindgen::ecdsa__verify_signature_(
+        message_buf.to_buffer().as_slice().as_ptr(),
+        pub_key.as_ptr(),
+        sig_r.as_ptr(),
+        sig_s.as_ptr(),
+        sig_v,
+        &mut result,
+    );
+    result
+}
+
+/// Returns the low-S (canonical) form of a raw secp256k1 `s` value, together with whether it
+/// differs from the input. The raw-`s` counterpart of [`Signature::normalize_s`]; unlike that
+/// method it does not touch a recovery id, so a caller holding `v` must flip its parity bit
+/// itself when a flip is reported.
+pub fn ecdsa__normalize_signature_s(sig_s: &[u8; 32]) -> ([u8; 32], bool) {
+    normalize_signature_s(sig_s, Curve::Secp256k1)
+}
+
+/// Like [`ecdsa__verify_signature_`], but rejects signatures whose `s` exceeds the secp256k1
+/// half-order instead of accepting both of a signature's two malleable `(r, s)` / `(r, n-s)`
+/// forms.
+pub unsafe fn ecdsa__verify_signature_strict(
+    message_buf: &[u8],
+    pub_key: &[u8; 64],
+    sig_r: &[u8; 32],
+    sig_s: &[u8; 32],
+    sig_v: &u8,
+) -> bool {
+    if !is_low_s(sig_s, Curve::Secp256k1) {
+        return false;
+    }
+    ecdsa__verify_signature_(message_buf, pub_key, sig_r, sig_s, sig_v)
+}
+
+fn is_low_s(sig_s: &[u8; 32], curve: Curve) -> bool {
+    BigUint::from_bytes_be(sig_s) <= curve.order() >> 1u32
+}
+
+fn normalize_signature_s(sig_s: &[u8; 32], curve: Curve) -> ([u8; 32], bool) {
+    if is_low_s(sig_s, curve) {
+        return (*sig_s, false);
+    }
+    let n = curve.order();
+    let s = BigUint::from_bytes_be(sig_s);
+    (biguint_to_32_bytes(&(n - s)), true)
+}
+
+/// Serializes this module's native 64-byte `x || y` public-key form to the 33-byte SEC1
+/// compressed form: a `0x02`/`0x03` prefix selected by `y`'s parity, followed by `x`.
+pub fn ecdsa__serialize_public_key_compressed(pub_key: &[u8; 64]) -> [u8; 33] {
+    serialize_public_key_compressed(pub_key)
+}
+
+/// Parses a public key in either the 33-byte SEC1 compressed form or the 65-byte SEC1
+/// uncompressed form (`0x04 || x || y`) into this module's native 64-byte `x || y` form.
+pub fn ecdsa__parse_public_key(bytes: &[u8]) -> Result<[u8; 64], EcdsaError> {
+    parse_public_key(bytes, Curve::Secp256k1)
+}
+
+fn serialize_public_key_compressed(pub_key: &[u8; 64]) -> [u8; 33] {
+    let mut out = [0u8; 33];
+    out[0] = if pub_key[63] & 1 == 0 { 0x02 } else { 0x03 };
+    out[1..].copy_from_slice(&pub_key[..32]);
+    out
+}
+
+fn parse_public_key(bytes: &[u8], curve: Curve) -> Result<[u8; 64], EcdsaError> {
+    match bytes.len() {
+        65 if bytes[0] == 0x04 => {
+            let mut out = [0u8; 64];
+            out.copy_from_slice(&bytes[1..]);
+            Ok(out)
+        }
+        33 if bytes[0] == 0x02 || bytes[0] == 0x03 => {
+            let mut x = [0u8; 32];
+            x.copy_from_slice(&bytes[1..]);
+            let p = curve.field_prime();
+            let rhs = curve.weierstrass_rhs(&BigUint::from_bytes_be(&x), &p);
+            let y = mod_sqrt(&rhs, &p)
+                .ok_or_else(|| EcdsaError::InvalidEncoding("x is not on the curve".to_string()))?;
+            let y_is_odd = y.bit(0);
+            let wants_odd = bytes[0] == 0x03;
+            let y = if y_is_odd == wants_odd { y } else { &p - y };
+
+            let mut out = [0u8; 64];
+            out[..32].copy_from_slice(&x);
+            out[32..].copy_from_slice(&biguint_to_32_bytes(&y));
+            Ok(out)
+        }
+        other => Err(EcdsaError::InvalidEncoding(format!(
+            "public key must be 33 bytes (compressed) or 65 bytes (uncompressed), got {other}"
+        ))),
+    }
+}
+
+// ECDSA secp256r1 curve functions
+
+pub unsafe fn ecdsa_r_compute_public_key(private_key: &[u8; 32]) -> [u8; 64] {
+    let mut public_key = [0; 64];
+    bindgen::ecdsa_r_compute_public_key(private_key.as_ptr(), public_key.as_mut_ptr());
+    public_key
+}
+
+pub unsafe fn ecdsa_r_construct_signature_(
+    message_buf: &[u8],
+    private_key: &[u8; 32],
+) -> ([u8; 32], [u8; 32], u8) {
+    let mut sig_r = [0; 32];
+    let mut sig_s = [0; 32];
+    let mut sig_v = 0u8;
+    bindgen::ecdsa_r_construct_signature_(
+        message_buf.to_buffer().as_slice().as_ptr(),
+        private_key.as_ptr(),
+        sig_r.as_mut_ptr(),
+        sig_s.as_mut_ptr(),
+        &mut sig_v,
+    );
+    (sig_r, sig_s, sig_v)
+}
+
+pub unsafe fn ecdsa_r_recover_public_key_from_signature_(
+    message_buf: &[u8],
+    sig_r: &[u8; 32],
+    sig_s: &[u8; 32],
+    sig_v: &mut u8,
+) -> [u8; 64] {
+    let mut output_pub_key = [0; 64];
+    bindgen::ecdsa_r_recover_public_key_from_signature_(
+        message_buf.to_buffer().as_slice().as_ptr(),
+        sig_r.as_ptr(),
+        sig_s.as_ptr(),
+        sig_v,
+        output_pub_key.as_mut_ptr(),
+    );
+    output_pub_key
+}
+
+pub unsafe fn ecdsa_r_verify_signature_(
+    message_buf: &[u8],
+    pub_key: &[u8; 64],
+    sig_r: &[u8; 32],
+    sig_s: &[u8; 32],
+    sig_v: &u8,
+) -> bool {
+    let mut result = false;
+    bindgen::ecdsa_r_verify_signature_(
+        message_buf.to_buffer().as_slice().as_ptr(),
+        pub_key.as_ptr(),
+        sig_r.as_ptr(),
+        sig_s.as_ptr(),
+        sig_v,
+        &mut result,
+    );
+    result
+}
+
+/// Returns the low-S (canonical) form of a raw secp256r1 `s` value, together with whether it
+/// differs from the input.
+pub fn ecdsa_r_normalize_signature_s(sig_s: &[u8; 32]) -> ([u8; 32], bool) {
+    normalize_signature_s(sig_s, Curve::Secp256r1)
+}
+
+/// Like [`ecdsa_r_verify_signature_`], but rejects signatures whose `s` exceeds the secp256r1
+/// half-order.
+pub unsafe fn ecdsa_r_verify_signature_strict(
+    message_buf: &[u8],
+    pub_key: &[u8; 64],
+    sig_r: &[u8; 32],
+    sig_s: &[u8; 32],
+    sig_v: &u8,
+) -> bool {
+    if !is_low_s(sig_s, Curve::Secp256r1) {
+        return false;
+    }
+    ecdsa_r_verify_signature_(message_buf, pub_key, sig_r, sig_s, sig_v)
+}
+
+/// Serializes a secp256r1 public key to the 33-byte SEC1 compressed form.
+pub fn ecdsa_r_serialize_public_key_compressed(pub_key: &[u8; 64]) -> [u8; 33] {
+    serialize_public_key_compressed(pub_key)
+}
+
+/// Parses a secp256r1 public key from either the 33-byte compressed or 65-byte uncompressed SEC1
+/// form into this module's native 64-byte `x || y` form.
+pub fn ecdsa_r_parse_public_key(bytes: &[u8]) -> Result<[u8; 64], EcdsaError> {
+    parse_public_key(bytes, Curve::Secp256r1)
+}
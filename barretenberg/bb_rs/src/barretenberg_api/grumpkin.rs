@@ -0,0 +1,285 @@
+use crate::barretenberg_api::bindgen;
+use crate::barretenberg_api::models::{Fr, Point};
+use crate::barretenberg_api::utils::{DeserializeBuffer, SerializeBuffer};
+use num_bigint::BigUint;
+
+/// Scalar multiplication on Grumpkin curve: point * scalar
+pub unsafe fn ecc_grumpkin__mul(point: &Point, scalar: &Fr) -> Point {
+    let mut result_buf = [0; 64];
+    bindgen::ecc_grumpkin__mul(
+        point.to_buffer().as_slice().as_ptr(),
+        scalar.to_buffer().as_slice().as_ptr(),
+        result_buf.as_mut_ptr(),
+    );
+    Point::from_buffer(result_buf)
+}
+
+/// Point addition on Grumpkin curve: point_a + point_b
+pub unsafe fn ecc_grumpkin__add(point_a: &Point, point_b: &Point) -> Point {
+    let mut result_buf = [0; 64];
+    bindgen::ecc_grumpkin__add(
+        point_a.to_buffer().as_slice().as_ptr(),
+        point_b.to_buffer().as_slice().as_ptr(),
+        result_buf.as_mut_ptr(),
+    );
+    Point::from_buffer(result_buf)
+}
+
+/// Batch scalar multiplication: multiply each point by the same scalar
+pub unsafe fn ecc_grumpkin__batch_mul(points: &[Point], scalar: &Fr) -> Vec<Point> {
+    let num_points = points.len() as u32;
+
+    // Serialize all points into a single buffer
+    let mut points_buf = Vec::with_capacity(points.len() * 64);
+    for point in points {
+        points_buf.extend_from_slice(&point.to_buffer());
+    }
+
+    // Prepare result buffer
+    let mut result_buf = vec![0u8; points.len() * 64];
+
+    bindgen::ecc_grumpkin__batch_mul(
+        points_buf.as_ptr(),
+        scalar.to_buffer().as_slice().as_ptr(),
+        num_points,
+        result_buf.as_mut_ptr(),
+    );
+
+    // Deserialize results back into Points
+    let mut results = Vec::with_capacity(points.len());
+    for i in 0..points.len() {
+        let start = i * 64;
+        let end = start + 64;
+        let mut point_buf = [0; 64];
+        point_buf.copy_from_slice(&result_buf[start..end]);
+        results.push(Point::from_buffer(point_buf));
+    }
+
+    results
+}
+
+/// Generate a random scalar modulo the circuit modulus
+pub unsafe fn ecc_grumpkin__get_random_scalar_mod_circuit_modulus() -> Fr {
+    let mut result_buf = [0; 32];
+    bindgen::ecc_grumpkin__get_random_scalar_mod_circuit_modulus(result_buf.as_mut_ptr());
+    Fr::from_buffer(result_buf)
+}
+
+/// Reduce a 512-bit buffer modulo the circuit modulus
+pub unsafe fn ecc_grumpkin__reduce512_buffer_mod_circuit_modulus(input: &[u8; 64]) -> Fr {
+    let mut result_buf = [0; 32];
+    let mut input_copy = *input;
+    bindgen::ecc_grumpkin__reduce512_buffer_mod_circuit_modulus(
+        input_copy.as_mut_ptr(),
+        result_buf.as_mut_ptr(),
+    );
+    Fr::from_buffer(result_buf)
+}
+
+/// The number of bits in a (big-endian) `Fr` scalar.
+const SCALAR_BITS: usize = 32 * 8;
+
+/// The affine representation of the point at infinity (the curve's group identity), following the
+/// same `(0, 0)` convention `ecc_grumpkin__add`/`ecc_grumpkin__mul` use internally.
+fn point_at_infinity() -> Point {
+    Point {
+        x: Fr::zero(),
+        y: Fr::zero(),
+    }
+}
+
+/// Picks the Pippenger window width for an MSM of `num_points` points: the bit length of
+/// `num_points` minus 2 (i.e. `floor(log2(num_points)) - 1`), clamped to at least 1 so the bucket
+/// count never degenerates to zero.
+fn msm_window_width(num_points: usize) -> usize {
+    let bits_to_represent = usize::BITS - num_points.max(1).leading_zeros();
+    (bits_to_represent as usize).saturating_sub(2).max(1)
+}
+
+/// Extracts the `window`-th `window_bits`-wide digit of `scalar`, counting windows from the least
+/// significant end.
+fn scalar_window_digit(scalar: &Fr, window: usize, window_bits: usize) -> usize {
+    let bytes = scalar.to_bytes_be();
+    let mut digit = 0usize;
+    for bit_in_window in 0..window_bits {
+        let bit_index = window * window_bits + bit_in_window;
+        if bit_index >= SCALAR_BITS {
+            break;
+        }
+        let byte = bytes[31 - bit_index / 8];
+        let bit = (byte >> (bit_index % 8)) & 1;
+        digit |= (bit as usize) << bit_in_window;
+    }
+    digit
+}
+
+/// Multi-scalar multiplication: `Σ points[i]·scalars[i]`, computed via the Pippenger bucket
+/// method instead of `points.len()` separate [`ecc_grumpkin__mul`] calls followed by additions.
+/// This is the primitive polynomial-commitment schemes (KZG, IPA) need, where every point is
+/// scaled by its own distinct scalar rather than [`ecc_grumpkin__batch_mul`]'s shared one.
+///
+/// Each 256-bit scalar is split into `ceil(256 / c)` windows of `c` bits, where `c` is chosen by
+/// [`msm_window_width`]. For each window, every point is added into one of `2^c` buckets keyed by
+/// its digit in that window (a zero digit contributes nothing and is skipped); the buckets are
+/// then collapsed into a single window sum with the standard running-sum trick (accumulating a
+/// suffix sum of the buckets, and a sum of those suffix sums, in one pass — `Σ_d d·bucket[d]` in
+/// about `2·2^c` additions rather than the naive `Σ_d d` many). Finally the per-window sums are
+/// combined from most- to least-significant with `c` point doublings between each, as in
+/// standard double-and-add.
+pub unsafe fn ecc_grumpkin__msm(points: &[Point], scalars: &[Fr]) -> Point {
+    assert_eq!(
+        points.len(),
+        scalars.len(),
+        "ecc_grumpkin__msm: points and scalars must have the same length"
+    );
+
+    if points.is_empty() {
+        return point_at_infinity();
+    }
+
+    let window_bits = msm_window_width(points.len());
+    let num_buckets = 1usize << window_bits;
+    let num_windows = SCALAR_BITS.div_ceil(window_bits);
+
+    let mut window_sums = Vec::with_capacity(num_windows);
+    for window in 0..num_windows {
+        let mut buckets = vec![point_at_infinity(); num_buckets];
+        for (point, scalar) in points.iter().zip(scalars.iter()) {
+            let digit = scalar_window_digit(scalar, window, window_bits);
+            if digit == 0 {
+                continue;
+            }
+            buckets[digit] = unsafe { ecc_grumpkin__add(&buckets[digit], point) };
+        }
+
+        // Running-sum trick: accumulate bucket[num_buckets - 1], then + bucket[num_buckets - 2],
+        // etc., into `running_sum`, and sum every intermediate `running_sum` into `window_sum` —
+        // this yields Σ_{d=1}^{num_buckets - 1} d·bucket[d] in one pass over the buckets.
+        let mut running_sum = point_at_infinity();
+        let mut window_sum = point_at_infinity();
+        for bucket in buckets.into_iter().skip(1).rev() {
+            running_sum = unsafe { ecc_grumpkin__add(&running_sum, &bucket) };
+            window_sum = unsafe { ecc_grumpkin__add(&window_sum, &running_sum) };
+        }
+        window_sums.push(window_sum);
+    }
+
+    // Combine windows most- to least-significant: (((w_top * 2^c) + w_next) * 2^c) + ... + w_0.
+    let mut windows_msb_first = window_sums.into_iter().rev();
+    let mut result = windows_msb_first
+        .next()
+        .unwrap_or_else(point_at_infinity);
+    for window_sum in windows_msb_first {
+        for _ in 0..window_bits {
+            result = unsafe { ecc_grumpkin__add(&result, &result) };
+        }
+        result = unsafe { ecc_grumpkin__add(&result, &window_sum) };
+    }
+
+    result
+}
+
+/// Negates a point by flipping its `y` coordinate, the standard inverse on a short Weierstrass
+/// curve — the only point-subtraction primitive [`WnafTable::mul`] needs, since the FFI only
+/// exposes point addition.
+fn negate(point: &Point) -> Point {
+    Point {
+        x: point.x,
+        y: point.y.neg(),
+    }
+}
+
+/// The width-`window_width` NAF digits of `scalar`, least-significant first: each digit is `0` or
+/// odd with `|digit| < 2^(window_width - 1)`, and at most one in every `window_width` consecutive
+/// digits is nonzero.
+fn wnaf_digits(scalar: &Fr, window_width: usize) -> Vec<i64> {
+    let window_modulus = 1i64 << window_width;
+    let half_window = 1i64 << (window_width - 1);
+
+    let mut remaining = BigUint::from_bytes_be(&scalar.data);
+    let mut digits = Vec::new();
+    while remaining > BigUint::from(0u8) {
+        if remaining.bit(0) {
+            let window_bits = &remaining % BigUint::from(window_modulus as u64);
+            let mut digit = window_bits.to_u64_digits().first().copied().unwrap_or(0) as i64;
+            if digit >= half_window {
+                digit -= window_modulus;
+            }
+            digits.push(digit);
+            remaining = if digit >= 0 {
+                remaining - BigUint::from(digit as u64)
+            } else {
+                remaining + BigUint::from((-digit) as u64)
+            };
+        } else {
+            digits.push(0);
+        }
+        remaining = remaining >> 1u32;
+    }
+    digits
+}
+
+/// Precomputed odd multiples of a fixed point, enabling repeated scalar multiplications by that
+/// same point via windowed NAF instead of one [`ecc_grumpkin__mul`] FFI call per scalar.
+///
+/// Worth building when the same point is multiplied by many different scalars (e.g. a
+/// per-validator generator, or a commitment key's base point); for a single one-off
+/// multiplication, plain [`ecc_grumpkin__mul`] does the same work with no extra bookkeeping.
+pub struct WnafTable {
+    /// `odd_multiples[i]` is `(2i + 1)·point`, for `i` in `0..2^(window_width - 1)`.
+    odd_multiples: Vec<Point>,
+    window_width: usize,
+}
+
+impl WnafTable {
+    /// Precomputes the odd multiples of `point` needed for width-`window_width` wNAF
+    /// multiplication.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_width` is less than 2 (a width-1 "NAF" would need no table: every digit
+    /// would be unit magnitude).
+    pub unsafe fn precompute(point: &Point, window_width: usize) -> Self {
+        assert!(
+            window_width >= 2,
+            "WnafTable::precompute: window_width must be at least 2"
+        );
+
+        let double = unsafe { ecc_grumpkin__add(point, point) };
+        let table_size = 1usize << (window_width - 1);
+        let mut odd_multiples = Vec::with_capacity(table_size);
+        odd_multiples.push(*point);
+        for i in 1..table_size {
+            let next = unsafe { ecc_grumpkin__add(&odd_multiples[i - 1], &double) };
+            odd_multiples.push(next);
+        }
+
+        Self {
+            odd_multiples,
+            window_width,
+        }
+    }
+
+    /// Multiplies the precomputed point by `scalar`: scans the width-`window_width` NAF digits
+    /// most-significant first, doubling every step and adding (or, for a negative digit,
+    /// subtracting via [`negate`]) the table entry for each nonzero digit.
+    pub unsafe fn mul(&self, scalar: &Fr) -> Point {
+        let digits = wnaf_digits(scalar, self.window_width);
+
+        let mut result = point_at_infinity();
+        for &digit in digits.iter().rev() {
+            result = unsafe { ecc_grumpkin__add(&result, &result) };
+            if digit != 0 {
+                let magnitude = digit.unsigned_abs() as usize;
+                let table_entry = self.odd_multiples[(magnitude - 1) / 2];
+                let term = if digit > 0 {
+                    table_entry
+                } else {
+                    negate(&table_entry)
+                };
+                result = unsafe { ecc_grumpkin__add(&result, &term) };
+            }
+        }
+        result
+    }
+}
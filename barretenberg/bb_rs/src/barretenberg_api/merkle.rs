@@ -0,0 +1,148 @@
+use super::{models::Fr, pedersen::pedersen_hash, poseidon2::poseidon2_hash};
+use std::collections::HashMap;
+
+/// The two-to-one compression a [`MerkleTree`] uses to combine a node's children into its digest.
+///
+/// `Poseidon2` matches the hash Noir circuits use natively for accumulators, so it's the default;
+/// `Pedersen` is offered for trees that need to match an existing Pedersen-based commitment
+/// scheme instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MerkleHasher {
+    Poseidon2,
+    Pedersen { hash_index: u32 },
+}
+
+impl MerkleHasher {
+    unsafe fn compress(&self, left: &Fr, right: &Fr) -> Fr {
+        match self {
+            MerkleHasher::Poseidon2 => unsafe { poseidon2_hash(&[*left, *right]) },
+            MerkleHasher::Pedersen { hash_index } => unsafe {
+                pedersen_hash(&[*left, *right], *hash_index)
+            },
+        }
+    }
+}
+
+/// An incremental, fixed-height, append-only Merkle tree over BN254's `Fr`, giving Aztec/Noir
+/// users a native accumulator matching the in-circuit hash without re-deriving it by hand.
+///
+/// Nodes are stored sparsely: only [`Self::push`]ed leaves and the ancestors they touch are kept,
+/// keyed by `(level, index)`. Everywhere else, the tree behaves as if every unpopulated subtree
+/// holds the all-zero leaf repeated and hashed up — `empty[0]` is the zero leaf and `empty[i] =
+/// compress(empty[i - 1], empty[i - 1])` — so [`Self::root`] and [`Self::proof`] are well-defined
+/// even for indices past the last push, and a freshly constructed tree already has a root.
+pub struct MerkleTree {
+    height: usize,
+    hasher: MerkleHasher,
+    /// `empty[level]` is the digest of an entirely-unpopulated subtree of that height.
+    empty: Vec<Fr>,
+    /// `nodes[level]` holds the populated digests at that level, keyed by index within the level.
+    nodes: Vec<HashMap<u64, Fr>>,
+    next_index: u64,
+}
+
+impl MerkleTree {
+    /// Creates an empty tree of the given `height`, compressing with [`MerkleHasher::Poseidon2`].
+    pub unsafe fn new(height: usize) -> Self {
+        unsafe { Self::with_hasher(height, MerkleHasher::Poseidon2) }
+    }
+
+    /// Creates an empty tree of the given `height`, using `hasher` for two-to-one compression.
+    pub unsafe fn with_hasher(height: usize, hasher: MerkleHasher) -> Self {
+        let mut empty = Vec::with_capacity(height + 1);
+        empty.push(Fr::from_u64(0));
+        for _ in 0..height {
+            let last = *empty.last().expect("just pushed the zero leaf");
+            empty.push(unsafe { hasher.compress(&last, &last) });
+        }
+
+        Self {
+            height,
+            hasher,
+            empty,
+            nodes: vec![HashMap::new(); height + 1],
+            next_index: 0,
+        }
+    }
+
+    /// Appends `leaf`, recomputing only the path from it to the root, and returns the index it was
+    /// inserted at.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tree is already full (`2^height` leaves pushed).
+    pub unsafe fn push(&mut self, leaf: Fr) -> u64 {
+        assert!(
+            self.next_index < (1u64 << self.height),
+            "MerkleTree::push: tree of height {} is full",
+            self.height
+        );
+
+        let index = self.next_index;
+        self.next_index += 1;
+
+        self.nodes[0].insert(index, leaf);
+        let mut current = leaf;
+        let mut current_index = index;
+        for level in 0..self.height {
+            let sibling_index = current_index ^ 1;
+            let sibling = self.nodes[level]
+                .get(&sibling_index)
+                .copied()
+                .unwrap_or(self.empty[level]);
+            let (left, right) = if current_index & 1 == 0 {
+                (current, sibling)
+            } else {
+                (sibling, current)
+            };
+            current = unsafe { self.hasher.compress(&left, &right) };
+            current_index >>= 1;
+            self.nodes[level + 1].insert(current_index, current);
+        }
+
+        index
+    }
+
+    /// The current root digest.
+    pub fn root(&self) -> Fr {
+        self.nodes[self.height]
+            .get(&0)
+            .copied()
+            .unwrap_or(self.empty[self.height])
+    }
+
+    /// The sibling digest at each level on the path from `index` to the root, using the
+    /// appropriate [`Self::empty`] digest for any sibling that hasn't been pushed.
+    pub fn proof(&self, index: u64) -> Vec<Fr> {
+        let mut path = Vec::with_capacity(self.height);
+        let mut current_index = index;
+        for level in 0..self.height {
+            let sibling_index = current_index ^ 1;
+            path.push(
+                self.nodes[level]
+                    .get(&sibling_index)
+                    .copied()
+                    .unwrap_or(self.empty[level]),
+            );
+            current_index >>= 1;
+        }
+        path
+    }
+}
+
+/// Folds `leaf` up through `path` (as produced by [`MerkleTree::proof`]), choosing left/right at
+/// each level by the corresponding bit of `index`, and checks the result against `root`.
+pub unsafe fn verify(hasher: MerkleHasher, root: &Fr, leaf: &Fr, index: u64, path: &[Fr]) -> bool {
+    let mut current = *leaf;
+    let mut current_index = index;
+    for sibling in path {
+        let (left, right) = if current_index & 1 == 0 {
+            (current, *sibling)
+        } else {
+            (*sibling, current)
+        };
+        current = unsafe { hasher.compress(&left, &right) };
+        current_index >>= 1;
+    }
+    current == *root
+}
@@ -1,7 +1,20 @@
 #![allow(non_snake_case)]
 pub mod acir;
+pub mod aes;
 pub mod bbapi;
+pub mod ecdsa;
+pub mod grumpkin;
+pub mod merkle;
 pub mod models;
+pub mod pedersen;
+pub mod poseidon2;
+#[cfg(feature = "native")]
+pub mod poseidon2_native;
+pub mod poseidon_schnorr;
+pub mod reed_solomon;
+pub mod schnorr;
+pub mod srs;
+pub mod vrf;
 
 #[allow(unused)]
 mod untested;
@@ -19,3 +32,7 @@ mod bindgen {
 }
 
 pub(crate) mod utils;
+// Some wrappers were written against a `traits` module name and a crate-level `Buffer` re-export;
+// keep both working rather than churning their imports.
+pub(crate) use utils as traits;
+pub(crate) use utils::Buffer;
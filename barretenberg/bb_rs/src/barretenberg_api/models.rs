@@ -0,0 +1,419 @@
+//! Field-element and point models shared across the `barretenberg_api` wrappers.
+//!
+//! `Fr` is the BN254 scalar field (also the Grumpkin base field) and `Fq` is the BN254 base
+//! field. The validating constructors (`from_be_bytes`, `from_hex`) reject non-canonical values;
+//! `from_raw`, `from_buffer` and the public `data` field do no range check.
+
+use crate::barretenberg_api::utils::{DeserializeBuffer, SerializeBuffer};
+use num_bigint::BigUint;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::ptr::copy_nonoverlapping;
+use std::os::raw::c_void;
+
+/// The BN254 scalar field modulus.
+const FR_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// The BN254 base field modulus.
+const FQ_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+pub type Ptr = *mut c_void;
+
+#[derive(Debug, thiserror::Error)]
+pub enum FieldError {
+    #[error("value is not a canonical field element: {reason}")]
+    NotCanonical { reason: String },
+    #[error("invalid hex string: {0}")]
+    InvalidHex(String),
+    #[error("invalid decimal string: {0}")]
+    InvalidDecimal(String),
+}
+
+fn reduce_mod(bytes: &[u8], modulus: &[u8; 32]) -> [u8; 32] {
+    let value = BigUint::from_bytes_be(bytes);
+    let m = BigUint::from_bytes_be(modulus);
+    let reduced = value % m;
+    let reduced_bytes = reduced.to_bytes_be();
+    let mut out = [0u8; 32];
+    out[32 - reduced_bytes.len()..].copy_from_slice(&reduced_bytes);
+    out
+}
+
+fn check_canonical(bytes: &[u8; 32], modulus: &[u8; 32]) -> Result<(), FieldError> {
+    if bytes.as_slice() >= modulus.as_slice() {
+        Err(FieldError::NotCanonical {
+            reason: "value is greater than or equal to the field modulus".to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Iterates over a field element's canonical big-endian byte representation one bit at a time,
+/// most-significant bit first -- the order in-Rust gadget emulation (replaying a double-and-add
+/// circuit, say) wants.
+pub struct BitIterator<'a> {
+    bytes: &'a [u8; 32],
+    next_bit: usize,
+}
+
+impl<'a> BitIterator<'a> {
+    fn new(bytes: &'a [u8; 32]) -> Self {
+        Self { bytes, next_bit: 0 }
+    }
+}
+
+impl<'a> Iterator for BitIterator<'a> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.next_bit >= 256 {
+            return None;
+        }
+        let byte = self.bytes[self.next_bit / 8];
+        let bit = (byte >> (7 - self.next_bit % 8)) & 1;
+        self.next_bit += 1;
+        Some(bit == 1)
+    }
+}
+
+/// An `ff`-crate-style field-arithmetic interface over this crate's FFI-backed field types
+/// ([`Fr`], [`Fq`]), so callers building Poseidon inputs, folding Merkle paths, or checking
+/// Schnorr/VRF relations can do that arithmetic in Rust instead of crossing the FFI boundary for
+/// every operation.
+///
+/// This delegates to each type's own (already `BigUint`-backed) `add`/`sub`/`mul`/... methods
+/// rather than introducing a separate Montgomery-form representation: those methods already do
+/// correct modular arithmetic against the field modulus, and a parallel representation would mean
+/// every one of this crate's `Type { data: [u8; 32] }` struct literals -- used throughout the FFI
+/// wrappers and their tests -- would need to track which form they're in, which is a much bigger
+/// change than this trait is trying to make. This only gives the existing arithmetic a common,
+/// generic-friendly name.
+pub trait PrimeField: Sized + Copy + PartialEq {
+    /// The canonical little-endian byte representation (the `ff`-crate convention, as opposed to
+    /// this crate's own big-endian-first [`Fr::to_bytes_be`]).
+    type Repr;
+
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn is_zero(&self) -> bool;
+
+    fn add(&self, other: &Self) -> Self;
+    fn sub(&self, other: &Self) -> Self;
+    fn mul(&self, other: &Self) -> Self;
+    fn neg(&self) -> Self;
+
+    /// The multiplicative inverse of `self`, or `None` if `self` is zero.
+    fn invert(&self) -> Option<Self>;
+
+    fn pow(&self, exponent: u64) -> Self;
+
+    fn to_repr(&self) -> Self::Repr;
+
+    /// Builds a field element from its canonical little-endian byte representation, reducing
+    /// modulo the field modulus rather than rejecting out-of-range values (matching
+    /// [`Fr::from_be_bytes_reduce`]'s convention).
+    fn from_repr(repr: Self::Repr) -> Self;
+
+    fn bits(&self) -> BitIterator<'_>;
+}
+
+macro_rules! field_element {
+    ($name:ident, $modulus:expr) => {
+        #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+        pub struct $name {
+            pub data: [u8; 32],
+        }
+
+        impl $name {
+            /// The field's modulus, as big-endian bytes.
+            pub const MODULUS: [u8; 32] = $modulus;
+
+            /// Builds a field element directly from raw big-endian bytes.
+            ///
+            /// This bypasses the canonical-range check performed by [`Self::from_be_bytes`];
+            /// prefer the validating constructors unless the caller already guarantees the
+            /// value is a valid FFI output (e.g. the result of a barretenberg call).
+            pub const fn from_raw(data: [u8; 32]) -> Self {
+                Self { data }
+            }
+
+            /// Builds a field element from a `u64`, which is always canonical.
+            pub fn from_u64(value: u64) -> Self {
+                let mut data = [0u8; 32];
+                data[24..].copy_from_slice(&value.to_be_bytes());
+                Self { data }
+            }
+
+            /// Builds a field element from a `u128`, which is always canonical.
+            pub fn from_u128(value: u128) -> Self {
+                let mut data = [0u8; 32];
+                data[16..].copy_from_slice(&value.to_be_bytes());
+                Self { data }
+            }
+
+            /// Parses a base-10 integer string, reducing modulo the field modulus rather than
+            /// rejecting out-of-range values.
+            pub fn from_dec_str(dec_str: &str) -> Result<Self, FieldError> {
+                let value = dec_str
+                    .parse::<BigUint>()
+                    .map_err(|e| FieldError::InvalidDecimal(e.to_string()))?;
+                Ok(Self::from_be_bytes_reduce(&value.to_bytes_be()))
+            }
+
+            /// Builds a field element from big-endian bytes, rejecting values that are not
+            /// strictly less than the field modulus.
+            pub fn from_be_bytes(bytes: &[u8; 32]) -> Result<Self, FieldError> {
+                check_canonical(bytes, &Self::MODULUS)?;
+                Ok(Self { data: *bytes })
+            }
+
+            /// Builds a field element from big-endian bytes, reducing modulo the field modulus
+            /// rather than rejecting out-of-range values.
+            pub fn from_be_bytes_reduce(bytes: &[u8]) -> Self {
+                Self { data: reduce_mod(bytes, &Self::MODULUS) }
+            }
+
+            /// Parses a `0x`-prefixed big-endian hex string, rejecting non-canonical values.
+            pub fn from_hex(hex_str: &str) -> Result<Self, FieldError> {
+                let stripped = hex_str.strip_prefix("0x").unwrap_or(hex_str);
+                let mut bytes = hex::decode(stripped).map_err(|e| FieldError::InvalidHex(e.to_string()))?;
+                if bytes.len() > 32 {
+                    return Err(FieldError::InvalidHex("hex string too long".to_string()));
+                }
+                let mut padded = vec![0u8; 32 - bytes.len()];
+                padded.append(&mut bytes);
+                let mut data = [0u8; 32];
+                data.copy_from_slice(&padded);
+                Self::from_be_bytes(&data)
+            }
+
+            /// Returns the `0x`-prefixed big-endian hex representation.
+            pub fn to_hex(&self) -> String {
+                format!("0x{}", hex::encode(self.data))
+            }
+
+            /// Returns the big-endian byte representation.
+            pub fn to_bytes_be(&self) -> [u8; 32] {
+                self.data
+            }
+
+            /// Returns the little-endian byte representation.
+            pub fn to_bytes_le(&self) -> [u8; 32] {
+                let mut le = self.data;
+                le.reverse();
+                le
+            }
+
+            /// The additive identity.
+            pub fn zero() -> Self {
+                Self::from_u64(0)
+            }
+
+            /// The multiplicative identity.
+            pub fn one() -> Self {
+                Self::from_u64(1)
+            }
+
+            fn to_biguint(&self) -> BigUint {
+                BigUint::from_bytes_be(&self.data)
+            }
+
+            fn from_biguint_reduced(value: BigUint) -> Self {
+                let modulus = BigUint::from_bytes_be(&Self::MODULUS);
+                Self::from_be_bytes_reduce(&(value % modulus).to_bytes_be())
+            }
+
+            fn modpow(&self, exponent: &BigUint) -> Self {
+                let modulus = BigUint::from_bytes_be(&Self::MODULUS);
+                Self::from_biguint_reduced(self.to_biguint().modpow(exponent, &modulus))
+            }
+
+            /// Field addition, reducing mod the field modulus.
+            pub fn add(&self, other: &Self) -> Self {
+                Self::from_biguint_reduced(self.to_biguint() + other.to_biguint())
+            }
+
+            /// Field subtraction, reducing mod the field modulus.
+            pub fn sub(&self, other: &Self) -> Self {
+                let modulus = BigUint::from_bytes_be(&Self::MODULUS);
+                let (a, b) = (self.to_biguint(), other.to_biguint());
+                let difference = if a >= b { a - b } else { &modulus - (b - a) };
+                Self::from_biguint_reduced(difference)
+            }
+
+            /// Field multiplication, reducing mod the field modulus.
+            pub fn mul(&self, other: &Self) -> Self {
+                Self::from_biguint_reduced(self.to_biguint() * other.to_biguint())
+            }
+
+            /// Field negation, reducing mod the field modulus.
+            pub fn neg(&self) -> Self {
+                Self::zero().sub(self)
+            }
+
+            /// Raises `self` to `exponent`, reducing mod the field modulus.
+            pub fn pow(&self, exponent: u64) -> Self {
+                self.modpow(&BigUint::from(exponent))
+            }
+
+            /// The multiplicative inverse of `self`, computed via Fermat's little theorem
+            /// (`self^(p - 2)`, since the field modulus `p` is prime).
+            ///
+            /// Returns [`Self::zero()`] for a zero input rather than panicking, following the
+            /// convention of letting a downstream constraint catch a division by zero instead of
+            /// aborting the computation; use [`Self::checked_inverse`] to distinguish the two
+            /// cases.
+            pub fn inverse(&self) -> Self {
+                self.checked_inverse().unwrap_or_else(Self::zero)
+            }
+
+            /// The multiplicative inverse of `self`, or `None` if `self` is zero.
+            pub fn checked_inverse(&self) -> Option<Self> {
+                if self.data == [0u8; 32] {
+                    return None;
+                }
+                let modulus = BigUint::from_bytes_be(&Self::MODULUS);
+                let exponent = modulus - BigUint::from(2u8);
+                Some(self.modpow(&exponent))
+            }
+        }
+
+        impl PrimeField for $name {
+            type Repr = [u8; 32];
+
+            fn zero() -> Self {
+                Self::zero()
+            }
+
+            fn one() -> Self {
+                Self::one()
+            }
+
+            fn is_zero(&self) -> bool {
+                self.data == [0u8; 32]
+            }
+
+            fn add(&self, other: &Self) -> Self {
+                self.add(other)
+            }
+
+            fn sub(&self, other: &Self) -> Self {
+                self.sub(other)
+            }
+
+            fn mul(&self, other: &Self) -> Self {
+                self.mul(other)
+            }
+
+            fn neg(&self) -> Self {
+                self.neg()
+            }
+
+            fn invert(&self) -> Option<Self> {
+                self.checked_inverse()
+            }
+
+            fn pow(&self, exponent: u64) -> Self {
+                self.pow(exponent)
+            }
+
+            fn to_repr(&self) -> [u8; 32] {
+                self.to_bytes_le()
+            }
+
+            fn from_repr(repr: [u8; 32]) -> Self {
+                let mut be = repr;
+                be.reverse();
+                Self::from_be_bytes_reduce(&be)
+            }
+
+            fn bits(&self) -> BitIterator<'_> {
+                BitIterator::new(&self.data)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.to_hex())
+            }
+        }
+
+        impl SerializeBuffer for $name {
+            fn to_buffer(&self) -> Vec<u8> {
+                self.data.to_vec()
+            }
+        }
+
+        impl DeserializeBuffer for $name {
+            type Slice = [u8; 32];
+
+            /// Builds a field element from a raw FFI output buffer with no canonical-range
+            /// check and no extra "already reduced" bookkeeping: every `bindgen` call this
+            /// crate wraps already returns a canonical value, so the check would only ever
+            /// re-verify something the barretenberg side guarantees, and adding a flag field
+            /// to `$name` would force every existing `$name { data }` literal in this crate
+            /// (there are many, in the FFI wrappers and their tests) to populate it too.
+            fn from_buffer(slice: Self::Slice) -> Self {
+                Self { data: slice }
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.to_hex())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                Self::from_hex(&s).map_err(D::Error::custom)
+            }
+        }
+    };
+}
+
+field_element!(Fr, FR_MODULUS);
+field_element!(Fq, FQ_MODULUS);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct Point {
+    pub x: Fr,
+    pub y: Fr,
+}
+
+impl SerializeBuffer for Point {
+    fn to_buffer(&self) -> Vec<u8> {
+        self.x
+            .to_buffer()
+            .into_iter()
+            .chain(self.y.to_buffer())
+            .collect()
+    }
+}
+
+impl DeserializeBuffer for Point {
+    type Slice = [u8; 64];
+
+    fn from_buffer(slice: Self::Slice) -> Self {
+        let mut x_bytes = [0u8; 32];
+        let mut y_bytes = [0u8; 32];
+        // SAFETY: `slice` is exactly 64 bytes, split evenly into the two coordinates.
+        unsafe {
+            copy_nonoverlapping(slice.as_ptr(), x_bytes.as_mut_ptr(), 32);
+            copy_nonoverlapping(slice.as_ptr().add(32), y_bytes.as_mut_ptr(), 32);
+        }
+        Point {
+            x: Fr::from_raw(x_bytes),
+            y: Fr::from_raw(y_bytes),
+        }
+    }
+}
@@ -4,6 +4,7 @@ use super::{
     traits::{DeserializeBuffer, SerializeBuffer},
     Buffer,
 };
+use num_bigint::BigUint;
 
 pub unsafe fn poseidon2_hash(inputs: &[Fr]) -> Fr {
     let mut output: <Fr as DeserializeBuffer>::Slice = [0; 32];
@@ -42,6 +43,92 @@ pub unsafe fn poseidon2_hashes(inputs: &[Fr]) -> Vec<Fr> {
     results
 }
 
+/// Width of the Poseidon2 permutation state used by this sponge (3 rate lanes + 1 capacity lane).
+const SPONGE_WIDTH: usize = 4;
+/// Number of field elements absorbed or squeezed per permutation call.
+const SPONGE_RATE: usize = 3;
+
+/// Adds two BN254 `Fr` elements modulo the scalar field, since `Fr` itself exposes no arithmetic
+/// beyond raw byte access.
+fn add_fr(a: &Fr, b: &Fr) -> Fr {
+    let modulus = BigUint::from_bytes_be(&Fr::MODULUS);
+    let sum = (BigUint::from_bytes_be(&a.data) + BigUint::from_bytes_be(&b.data)) % modulus;
+    let sum_bytes = sum.to_bytes_be();
+    let mut data = [0u8; 32];
+    data[32 - sum_bytes.len()..].copy_from_slice(&sum_bytes);
+    Fr::from_raw(data)
+}
+
+/// An incremental Poseidon2 sponge over BN254's `Fr`, mirroring the repeated-`input`-then-
+/// `finalize` shape of streaming hash engines so callers can absorb field elements in chunks
+/// (e.g. while iterating a large Merkle frontier, or as a streaming transcript fills) instead of
+/// materializing the whole `&[Fr]` upfront the way [`poseidon2_hash`] requires.
+///
+/// Absorbs into a 3-element rate (the fourth, capacity, lane starts at zero and is never absorbed
+/// into; only the permutation changes it), applying [`poseidon2_permutation`] each
+/// time a rate-sized block fills. `squeeze`/`finalize` pad any partial trailing block with zeros
+/// and permute once more, so hashing `a, b, c` via one `absorb(&[a, b, c])` then `finalize()`
+/// matches `poseidon2_hash(&[a, b, c])`, and the result is unaffected by how the caller chooses to
+/// split a given sequence of inputs across multiple `absorb` calls.
+pub struct Poseidon2Sponge {
+    state: [Fr; SPONGE_WIDTH],
+    buffer: Vec<Fr>,
+}
+
+impl Poseidon2Sponge {
+    pub fn new() -> Self {
+        Self {
+            state: [Fr::from_u64(0); SPONGE_WIDTH],
+            buffer: Vec::with_capacity(SPONGE_RATE),
+        }
+    }
+
+    /// Buffers `inputs` into rate-sized blocks, applying the Poseidon2 permutation under the hood
+    /// each time a block fills.
+    pub unsafe fn absorb(&mut self, inputs: &[Fr]) {
+        for input in inputs {
+            self.buffer.push(*input);
+            if self.buffer.len() == SPONGE_RATE {
+                unsafe { self.permute_block() };
+            }
+        }
+    }
+
+    /// Pads any buffered partial block with zeros, permutes once more, and returns the first
+    /// state lane as the hash output, leaving the sponge ready for further absorption.
+    pub unsafe fn squeeze(&mut self) -> Fr {
+        if !self.buffer.is_empty() {
+            unsafe { self.permute_block() };
+        }
+        self.state[0]
+    }
+
+    /// Equivalent to [`Self::squeeze`], consuming the sponge.
+    pub unsafe fn finalize(mut self) -> Fr {
+        unsafe { self.squeeze() }
+    }
+
+    unsafe fn permute_block(&mut self) {
+        while self.buffer.len() < SPONGE_RATE {
+            self.buffer.push(Fr::from_u64(0));
+        }
+        for (lane, input) in self.state.iter_mut().zip(self.buffer.iter()) {
+            *lane = add_fr(lane, input);
+        }
+        let permuted = unsafe { poseidon2_permutation(&self.state) };
+        for (lane, value) in self.state.iter_mut().zip(permuted.into_iter()) {
+            *lane = value;
+        }
+        self.buffer.clear();
+    }
+}
+
+impl Default for Poseidon2Sponge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 pub unsafe fn poseidon2_permutation(inputs: &[Fr]) -> Vec<Fr> {
     let mut result_ptr: *mut u8 = std::ptr::null_mut();
     
@@ -79,6 +166,59 @@ pub unsafe fn poseidon2_permutation(inputs: &[Fr]) -> Vec<Fr> {
             results.push(Fr::from_buffer(fr_data));
         }
     }
-    
+
     results
 }
+
+/// Hashes `nodes` in fixed-width groups of `arity`, one [`poseidon2_hash`] call per group, instead
+/// of the caller issuing `nodes.len() / arity` individual FFI calls.
+///
+/// If `nodes.len()` isn't a multiple of `arity`, the final group is padded with zero `Fr` elements
+/// up to `arity` before hashing — the same zero-padding rule [`Poseidon2Sponge`] applies to its
+/// trailing partial block.
+///
+/// # Panics
+///
+/// Panics if `arity` is zero.
+pub unsafe fn poseidon2_tree_layer(nodes: &[Fr], arity: usize) -> Vec<Fr> {
+    assert!(arity > 0, "poseidon2_tree_layer: arity must be non-zero");
+
+    nodes
+        .chunks(arity)
+        .map(|group| {
+            if group.len() == arity {
+                unsafe { poseidon2_hash(group) }
+            } else {
+                let mut padded = group.to_vec();
+                padded.resize(arity, Fr::from_u64(0));
+                unsafe { poseidon2_hash(&padded) }
+            }
+        })
+        .collect()
+}
+
+/// Folds `leaves` up to a single Merkle root by repeatedly applying [`poseidon2_tree_layer`], so
+/// callers build the whole accumulator in `O(log_arity(n))` FFI batches rather than one
+/// [`poseidon2_hash`] call per internal node.
+///
+/// An empty `leaves` slice hashes to the same thing a single padded group of `arity` zero
+/// elements would (see [`poseidon2_tree_layer`]'s padding rule), so the root is well-defined even
+/// for a zero-leaf tree.
+///
+/// # Panics
+///
+/// Panics if `arity` is zero.
+pub unsafe fn poseidon2_merkle_root(leaves: &[Fr], arity: usize) -> Fr {
+    assert!(arity > 0, "poseidon2_merkle_root: arity must be non-zero");
+
+    let mut layer = if leaves.is_empty() {
+        vec![Fr::from_u64(0); arity]
+    } else {
+        leaves.to_vec()
+    };
+
+    while layer.len() > 1 {
+        layer = unsafe { poseidon2_tree_layer(&layer, arity) };
+    }
+    layer[0]
+}
@@ -0,0 +1,152 @@
+//! Pure-Rust, dependency-light BN254 Poseidon2 permutation (`t = 4`), intended as an FFI-free
+//! fallback to [`super::poseidon2::poseidon2_permutation`] for wasm and other environments where
+//! linking barretenberg's C++ isn't an option. Structured as an arkworks-style port, following
+//! `poseidon-ark`: `R_F = 8` full rounds (4 before the partial rounds, 4 after) and `R_P = 56`
+//! partial rounds, with the external/internal round structure described on each function below.
+//!
+//! Only compiled under the `native` cargo feature; the default build keeps using the FFI
+//! permutation in [`super::poseidon2`].
+//!
+//! # Incomplete: round constants
+//!
+//! [`FULL_ROUND_CONSTANTS`] and [`PARTIAL_ROUND_CONSTANTS`] below are zeroed placeholders, not
+//! barretenberg's real BN254 Poseidon2 parameter set — that table (8 vectors of 4 field elements
+//! for the full rounds, 56 scalars for the partial rounds) wasn't available to source correctly
+//! in the environment this was written in, and guessing plausible-looking constants would be
+//! worse than flagging the gap outright: it would make [`poseidon2_permutation_native`] produce
+//! confidently wrong hashes that happen to look like real output. Everything else here (the S-box,
+//! the external and internal matrices, and the round schedule) is wired up so that dropping in the
+//! real constants is the only remaining step; until then this function does not reproduce
+//! `poseidon2_permutation`, and the compatibility tests at the bottom of this file are `#[ignore]`d
+//! for exactly that reason rather than asserting against made-up numbers.
+
+use crate::barretenberg_api::models::Fr;
+use num_bigint::BigUint;
+
+const T: usize = 4;
+const ROUNDS_FULL: usize = 8;
+const ROUNDS_PARTIAL: usize = 56;
+
+/// External (full-round) MDS matrix, applied once before the first full round and again after
+/// every full round's S-box layer.
+const EXTERNAL_MATRIX: [[u64; T]; T] = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]];
+
+/// Diagonal `d` of the internal-round matrix `M_I = ones(4) + diag(d)`.
+const INTERNAL_DIAGONAL: [u64; T] = [2, 3, 1, 1];
+
+/// Per-full-round constant vectors. See the module doc comment: these are zeroed placeholders.
+const FULL_ROUND_CONSTANTS: [[Fr; T]; ROUNDS_FULL] = [[Fr::from_raw([0u8; 32]); T]; ROUNDS_FULL];
+
+/// Per-partial-round constants. See the module doc comment: these are zeroed placeholders.
+const PARTIAL_ROUND_CONSTANTS: [Fr; ROUNDS_PARTIAL] = [Fr::from_raw([0u8; 32]); ROUNDS_PARTIAL];
+
+fn fr_to_biguint(value: &Fr) -> BigUint {
+    BigUint::from_bytes_be(&value.data)
+}
+
+fn biguint_to_fr(value: BigUint) -> Fr {
+    let modulus = BigUint::from_bytes_be(&Fr::MODULUS);
+    let reduced = value % modulus;
+    let bytes = reduced.to_bytes_be();
+    let mut data = [0u8; 32];
+    data[32 - bytes.len()..].copy_from_slice(&bytes);
+    Fr::from_raw(data)
+}
+
+fn fr_add(a: &Fr, b: &Fr) -> Fr {
+    biguint_to_fr(fr_to_biguint(a) + fr_to_biguint(b))
+}
+
+fn fr_mul(a: &Fr, b: &Fr) -> Fr {
+    biguint_to_fr(fr_to_biguint(a) * fr_to_biguint(b))
+}
+
+fn fr_mul_small(a: &Fr, scalar: u64) -> Fr {
+    biguint_to_fr(fr_to_biguint(a) * BigUint::from(scalar))
+}
+
+/// `x^5`, the Poseidon2 S-box over BN254's scalar field.
+fn sbox(x: &Fr) -> Fr {
+    let x2 = fr_mul(x, x);
+    let x4 = fr_mul(&x2, &x2);
+    fr_mul(&x4, x)
+}
+
+/// Multiplies `state` by [`EXTERNAL_MATRIX`].
+fn apply_external_matrix(state: &mut [Fr; T]) {
+    let mut next = *state;
+    for (row, next_lane) in next.iter_mut().enumerate() {
+        let mut acc = fr_mul_small(&state[0], EXTERNAL_MATRIX[row][0]);
+        for col in 1..T {
+            acc = fr_add(&acc, &fr_mul_small(&state[col], EXTERNAL_MATRIX[row][col]));
+        }
+        *next_lane = acc;
+    }
+    *state = next;
+}
+
+/// Multiplies `state` by `M_I = ones(4) + diag(d)`: every lane becomes the sum of the whole state
+/// plus `d[lane] * state[lane]`.
+fn apply_internal_matrix(state: &mut [Fr; T]) {
+    let mut sum = state[0];
+    for value in &state[1..] {
+        sum = fr_add(&sum, value);
+    }
+    for (lane, diag) in state.iter_mut().zip(INTERNAL_DIAGONAL.iter()) {
+        *lane = fr_add(&sum, &fr_mul_small(lane, *diag));
+    }
+}
+
+/// BN254 Poseidon2 permutation over a 4-element state, computed entirely in Rust.
+///
+/// See the module doc comment: this reproduces the correct round structure, but runs with
+/// placeholder round constants, so its output does not yet match
+/// [`super::poseidon2::poseidon2_permutation`].
+pub fn poseidon2_permutation_native(inputs: &[Fr; T]) -> [Fr; T] {
+    let mut state = *inputs;
+    apply_external_matrix(&mut state);
+
+    let half_full = ROUNDS_FULL / 2;
+    for constants in &FULL_ROUND_CONSTANTS[..half_full] {
+        for (lane, constant) in state.iter_mut().zip(constants.iter()) {
+            *lane = sbox(&fr_add(lane, constant));
+        }
+        apply_external_matrix(&mut state);
+    }
+
+    for constant in &PARTIAL_ROUND_CONSTANTS {
+        state[0] = sbox(&fr_add(&state[0], constant));
+        apply_internal_matrix(&mut state);
+    }
+
+    for constants in &FULL_ROUND_CONSTANTS[half_full..] {
+        for (lane, constant) in state.iter_mut().zip(constants.iter()) {
+            *lane = sbox(&fr_add(lane, constant));
+        }
+        apply_external_matrix(&mut state);
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::poseidon2_permutation_native;
+    use crate::barretenberg_api::models::Fr;
+
+    // These mirror the FFI compatibility vectors in `tests/poseidon2_tests.rs`. They're ignored
+    // until real barretenberg round constants replace the placeholders above — see the module doc
+    // comment for why those constants couldn't be sourced here.
+    #[test]
+    #[ignore = "native permutation uses placeholder round constants; see module doc comment"]
+    fn test_poseidon2_permutation_native_js_compatibility_cpp() {
+        let inputs = [Fr::from_u64(0), Fr::from_u64(1), Fr::from_u64(2), Fr::from_u64(3)];
+        let results = poseidon2_permutation_native(&inputs);
+        let expected_0 = [
+            0x01, 0xbd, 0x53, 0x8c, 0x2e, 0xe0, 0x14, 0xed, 0x51, 0x41, 0xb2, 0x9e, 0x9a, 0xe2,
+            0x40, 0xbf, 0x8d, 0xb3, 0xfe, 0x5b, 0x9a, 0x38, 0x62, 0x9a, 0x96, 0x47, 0xcf, 0x8d,
+            0x76, 0xc0, 0x17, 0x37,
+        ];
+        assert_eq!(results[0].data, expected_0);
+    }
+}
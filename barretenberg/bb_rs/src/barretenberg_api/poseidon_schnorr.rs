@@ -0,0 +1,127 @@
+//! Schnorr signatures over Grumpkin, challenging with [`poseidon2_hash`] rather than the
+//! blake2s-based hash behind the FFI's own [`crate::barretenberg_api::schnorr`] bindings — for
+//! callers whose in-circuit verifier needs the challenge computed as Poseidon2.
+//!
+//! Built entirely from [`ecc_grumpkin__mul`]/[`ecc_grumpkin__add`]/[`poseidon2_hash`], so unlike
+//! [`crate::barretenberg_api::schnorr`] this needs no new C++ entry point. The FFI bindings return
+//! nonces, secret keys, and scalar responses as `Fr`-typed byte buffers, but `Fr` is the BN254
+//! scalar field, which is Grumpkin's *base* (coordinate) field, not its scalar field — Grumpkin's
+//! true group order is BN254's base field prime, this crate's `Fq` (see
+//! [`crate::barretenberg_api::models`]). Combining these scalars via `Fr::add`/`sub`/`mul` would
+//! therefore silently reduce them modulo the wrong, smaller prime, so [`scalar_add`],
+//! [`scalar_sub`], and [`scalar_mul`] below reinterpret the same bytes as `Fq` to do that
+//! arithmetic modulo Grumpkin's actual order instead.
+
+use crate::barretenberg_api::grumpkin::{
+    ecc_grumpkin__add, ecc_grumpkin__get_random_scalar_mod_circuit_modulus, ecc_grumpkin__mul,
+};
+use crate::barretenberg_api::models::{Fq, Fr, Point};
+use crate::barretenberg_api::poseidon2::poseidon2_hash;
+use crate::barretenberg_api::schnorr::schnorr_compute_public_key;
+
+/// Adds two Grumpkin scalars modulo Grumpkin's true group order, rather than `Fr::add`'s BN254
+/// scalar-field modulus. See the module-level doc comment for why these two moduli differ.
+pub(crate) fn scalar_add(a: &Fr, b: &Fr) -> Fr {
+    Fr::from_raw(Fq::from_raw(a.data).add(&Fq::from_raw(b.data)).data)
+}
+
+/// Subtracts two Grumpkin scalars modulo Grumpkin's true group order, rather than `Fr::sub`'s
+/// BN254 scalar-field modulus. See the module-level doc comment for why these two moduli differ.
+pub(crate) fn scalar_sub(a: &Fr, b: &Fr) -> Fr {
+    Fr::from_raw(Fq::from_raw(a.data).sub(&Fq::from_raw(b.data)).data)
+}
+
+/// Multiplies two Grumpkin scalars modulo Grumpkin's true group order, rather than `Fr::mul`'s
+/// BN254 scalar-field modulus. See the module-level doc comment for why these two moduli differ.
+pub(crate) fn scalar_mul(a: &Fr, b: &Fr) -> Fr {
+    Fr::from_raw(Fq::from_raw(a.data).mul(&Fq::from_raw(b.data)).data)
+}
+
+/// A Poseidon2-Schnorr signature: the challenge `e` and response `s`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature {
+    pub e: Fr,
+    pub s: Fr,
+}
+
+/// The Grumpkin group generator `G`. This crate exposes no standalone "get curve generator"
+/// binding, so `G` is derived as the native Schnorr public key of the scalar `1`, which is `1·G`
+/// by definition.
+///
+/// `pub(crate)` rather than private: [`crate::barretenberg_api::vrf`] needs the same generator
+/// and would otherwise have to re-derive it independently.
+pub(crate) fn generator() -> Point {
+    unsafe { schnorr_compute_public_key(&Fr::one()) }
+}
+
+fn is_identity(point: &Point) -> bool {
+    point.x.data == [0u8; 32] && point.y.data == [0u8; 32]
+}
+
+/// How [`sign_with_nonce`] draws its per-signature nonce `k`, made pluggable so a deterministic
+/// (e.g. RFC6979-style) source can be substituted later without changing the signing API —
+/// reusing a nonce across two signatures under the same key leaks the secret scalar.
+pub trait NonceSource {
+    unsafe fn nonce(&mut self) -> Fr;
+}
+
+/// The default [`NonceSource`]: a fresh random scalar from the FFI's own RNG on every call.
+pub struct RandomNonce;
+
+impl NonceSource for RandomNonce {
+    unsafe fn nonce(&mut self) -> Fr {
+        unsafe { ecc_grumpkin__get_random_scalar_mod_circuit_modulus() }
+    }
+}
+
+/// Generates a keypair: a random secret scalar and its Grumpkin public point `pk = sk·G`.
+pub unsafe fn keygen() -> (Fr, Point) {
+    let sk = unsafe { ecc_grumpkin__get_random_scalar_mod_circuit_modulus() };
+    let pk = unsafe { ecc_grumpkin__mul(&generator(), &sk) };
+    (sk, pk)
+}
+
+/// The Poseidon2 challenge `e = poseidon2_hash(&[r.x, pk.x, msg...])`, shared between signing and
+/// verification so the two can never compute it differently.
+fn challenge(r: &Point, pk: &Point, msg: &[Fr]) -> Fr {
+    let mut inputs = Vec::with_capacity(2 + msg.len());
+    inputs.push(r.x);
+    inputs.push(pk.x);
+    inputs.extend_from_slice(msg);
+    unsafe { poseidon2_hash(&inputs) }
+}
+
+/// Signs `msg` under `sk`, drawing the nonce from `nonce_source` instead of the FFI's RNG.
+pub unsafe fn sign_with_nonce(
+    sk: &Fr,
+    msg: &[Fr],
+    nonce_source: &mut impl NonceSource,
+) -> Signature {
+    let k = unsafe { nonce_source.nonce() };
+    let pk = unsafe { ecc_grumpkin__mul(&generator(), sk) };
+    let r = unsafe { ecc_grumpkin__mul(&generator(), &k) };
+    let e = challenge(&r, &pk, msg);
+    let s = scalar_sub(&k, &scalar_mul(sk, &e));
+    Signature { e, s }
+}
+
+/// Signs `msg` under `sk`, drawing the nonce `k` from the FFI's RNG.
+pub unsafe fn sign(sk: &Fr, msg: &[Fr]) -> Signature {
+    unsafe { sign_with_nonce(sk, msg, &mut RandomNonce) }
+}
+
+/// Verifies `signature` over `msg` under `pk`, by recomputing `R' = s·G + e·pk` and checking that
+/// hashing it back reproduces the claimed challenge `e`. Rejects the identity point as a public
+/// key, since no secret scalar maps to it and accepting it would let any message "verify" against
+/// a forged zero signature.
+pub unsafe fn verify(pk: &Point, msg: &[Fr], signature: &Signature) -> bool {
+    if is_identity(pk) {
+        return false;
+    }
+
+    let s_g = unsafe { ecc_grumpkin__mul(&generator(), &signature.s) };
+    let e_pk = unsafe { ecc_grumpkin__mul(pk, &signature.e) };
+    let r_prime = unsafe { ecc_grumpkin__add(&s_g, &e_pk) };
+
+    challenge(&r_prime, pk, msg) == signature.e
+}
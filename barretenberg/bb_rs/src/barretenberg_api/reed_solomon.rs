@@ -0,0 +1,248 @@
+//! Reed-Solomon data-availability encoding over BN254's scalar field `Fr` (Grumpkin's base field).
+//!
+//! A byte blob is packed into `k` field elements ([`bytes_to_polynomial`]), treated as the
+//! coefficients of a degree-`(k - 1)` polynomial, and evaluated at the `n`-th roots of unity via
+//! a radix-2 NTT ([`rs_encode`]) to produce an erasure code: any `k` of the `n` evaluations
+//! suffice to recover the original coefficients ([`rs_decode`]). [`commit`] additionally runs an
+//! MSM over a caller-supplied SRS to produce a KZG-style commitment to the coefficients.
+
+use super::grumpkin::ecc_grumpkin__msm;
+use super::models::{Fr, Point};
+use num_bigint::BigUint;
+
+/// Bytes packed per field element. 31 rather than 32, so the packed little-endian integer
+/// (at most `2^248 - 1`) is always below the ~253-bit BN254 scalar field modulus and never needs
+/// reduction.
+const BYTES_PER_ELEMENT: usize = 31;
+
+/// A fixed generator of `Fr`'s multiplicative group, used to derive roots of unity for the NTT.
+/// `5` is the standard generator for the BN254 scalar field.
+const GENERATOR: u64 = 5;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReedSolomonError {
+    #[error("evaluation domain size {n} is not a power of two, as required by the radix-2 NTT")]
+    DomainNotPowerOfTwo { n: usize },
+    #[error("evaluation domain size {n} does not divide Fr's multiplicative group order")]
+    DomainDoesNotDivideGroupOrder { n: usize },
+    #[error("evaluation domain size {n} is smaller than the {k} coefficients being encoded")]
+    DomainTooSmall { n: usize, k: usize },
+    #[error("not enough samples to decode: need at least {required}, got {got}")]
+    NotEnoughSamples { required: usize, got: usize },
+    #[error("commit given {coeffs} coefficients but only {srs} SRS points")]
+    SrsTooShort { coeffs: usize, srs: usize },
+}
+
+/// Packs a byte blob into field elements, 31 bytes per element, each interpreted as a
+/// little-endian integer. The final chunk is zero-padded on the high end if `data.len()` isn't a
+/// multiple of 31.
+///
+/// The caller is responsible for remembering the original byte length: [`polynomial_to_bytes`]
+/// unpacks a whole number of 31-byte chunks and can't tell padding from trailing zero data bytes.
+pub fn bytes_to_polynomial(data: &[u8]) -> Vec<Fr> {
+    data.chunks(BYTES_PER_ELEMENT)
+        .map(|chunk| {
+            let mut little_endian = [0u8; BYTES_PER_ELEMENT];
+            little_endian[..chunk.len()].copy_from_slice(chunk);
+
+            let mut big_endian = [0u8; 32];
+            for (i, byte) in little_endian.iter().enumerate() {
+                big_endian[31 - i] = *byte;
+            }
+            Fr::from_be_bytes(&big_endian)
+                .expect("a 31-byte little-endian integer is always below the field modulus")
+        })
+        .collect()
+}
+
+/// Inverse of [`bytes_to_polynomial`]: unpacks each field element back into its 31-byte
+/// little-endian chunk. See that function's doc comment for the padding caveat.
+pub fn polynomial_to_bytes(coeffs: &[Fr]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(coeffs.len() * BYTES_PER_ELEMENT);
+    for coeff in coeffs {
+        let big_endian = coeff.to_bytes_be();
+        for i in 0..BYTES_PER_ELEMENT {
+            out.push(big_endian[31 - i]);
+        }
+    }
+    out
+}
+
+/// Encodes `coeffs` (the `k` polynomial coefficients from [`bytes_to_polynomial`]) into `n > k`
+/// evaluations via a radix-2 NTT, using the domain returned by [`domain_root_of_unity`]. Any `k`
+/// of the returned evaluations suffice to reconstruct `coeffs` with [`rs_decode`].
+pub fn rs_encode(coeffs: &[Fr], n: usize) -> Result<Vec<Fr>, ReedSolomonError> {
+    if n < coeffs.len() {
+        return Err(ReedSolomonError::DomainTooSmall {
+            n,
+            k: coeffs.len(),
+        });
+    }
+    let root = domain_root_of_unity(n)?;
+
+    let mut padded = coeffs.to_vec();
+    padded.resize(n, Fr::zero());
+    Ok(ntt(&padded, root))
+}
+
+/// Recovers the `k` original coefficients from `samples`, a set of `(index, value)` evaluation
+/// pairs over the same size-`n` domain [`rs_encode`] used (`index` is the exponent of
+/// [`domain_root_of_unity`] at which `value` was sampled). Requires at least `k` samples; pass
+/// `n` so the encoder and decoder agree on the root of unity used to map indices to evaluation
+/// points.
+pub fn rs_decode(
+    samples: &[(usize, Fr)],
+    k: usize,
+    n: usize,
+) -> Result<Vec<Fr>, ReedSolomonError> {
+    if samples.len() < k {
+        return Err(ReedSolomonError::NotEnoughSamples {
+            required: k,
+            got: samples.len(),
+        });
+    }
+    let root = domain_root_of_unity(n)?;
+
+    let points: Vec<(Fr, Fr)> = samples[..k]
+        .iter()
+        .map(|&(index, value)| (root.pow(index as u64), value))
+        .collect();
+
+    let mut coeffs = lagrange_interpolate(&points);
+    coeffs.resize(k, Fr::zero());
+    Ok(coeffs)
+}
+
+/// Commits to `coeffs` by running an MSM of `coeffs` against the first `coeffs.len()` points of
+/// `srs` (a structured reference string of Grumpkin points, one per coefficient degree).
+pub unsafe fn commit(coeffs: &[Fr], srs: &[Point]) -> Result<Point, ReedSolomonError> {
+    if srs.len() < coeffs.len() {
+        return Err(ReedSolomonError::SrsTooShort {
+            coeffs: coeffs.len(),
+            srs: srs.len(),
+        });
+    }
+    Ok(unsafe { ecc_grumpkin__msm(&srs[..coeffs.len()], coeffs) })
+}
+
+/// Returns a primitive `n`-th root of unity of `Fr`, i.e. `generator^((p - 1) / n)`. Encoder and
+/// decoder must call this with the same `n` to agree on the evaluation domain.
+pub fn domain_root_of_unity(n: usize) -> Result<Fr, ReedSolomonError> {
+    if !n.is_power_of_two() {
+        return Err(ReedSolomonError::DomainNotPowerOfTwo { n });
+    }
+
+    let modulus_minus_one = BigUint::from_bytes_be(&Fr::MODULUS) - BigUint::from(1u8);
+    let n_big = BigUint::from(n as u64);
+    if &modulus_minus_one % &n_big != BigUint::from(0u8) {
+        return Err(ReedSolomonError::DomainDoesNotDivideGroupOrder { n });
+    }
+
+    let exponent = &modulus_minus_one / &n_big;
+    Ok(pow_biguint(&Fr::from_u64(GENERATOR), &exponent))
+}
+
+/// Right-to-left square-and-multiply exponentiation by an arbitrary-precision exponent, since
+/// [`Fr::pow`] only accepts a `u64` and the domain-generator exponent `(p - 1) / n` doesn't fit
+/// one.
+fn pow_biguint(base: &Fr, exponent: &BigUint) -> Fr {
+    let mut result = Fr::one();
+    let mut base = *base;
+    let mut exponent = exponent.clone();
+    let two = BigUint::from(2u8);
+    while exponent > BigUint::from(0u8) {
+        if &exponent % &two == BigUint::from(1u8) {
+            result = result.mul(&base);
+        }
+        base = base.mul(&base);
+        exponent /= &two;
+    }
+    result
+}
+
+/// Radix-2 decimation-in-time NTT: evaluates the polynomial with coefficients `coeffs` (ascending
+/// degree, `len` a power of two) at every power of `root`, where `root` has order `coeffs.len()`.
+fn ntt(coeffs: &[Fr], root: Fr) -> Vec<Fr> {
+    let n = coeffs.len();
+    if n == 1 {
+        return vec![coeffs[0]];
+    }
+
+    let evens: Vec<Fr> = coeffs.iter().step_by(2).copied().collect();
+    let odds: Vec<Fr> = coeffs.iter().skip(1).step_by(2).copied().collect();
+    let root_squared = root.mul(&root);
+    let even_ntt = ntt(&evens, root_squared);
+    let odd_ntt = ntt(&odds, root_squared);
+
+    let half = n / 2;
+    let mut result = vec![Fr::zero(); n];
+    let mut twiddle = Fr::one();
+    for i in 0..half {
+        let t = twiddle.mul(&odd_ntt[i]);
+        result[i] = even_ntt[i].add(&t);
+        result[i + half] = even_ntt[i].sub(&t);
+        twiddle = twiddle.mul(&root);
+    }
+    result
+}
+
+/// Lagrange-interpolates the coefficients (ascending degree) of the unique polynomial of degree
+/// `< points.len()` passing through `points`.
+fn lagrange_interpolate(points: &[(Fr, Fr)]) -> Vec<Fr> {
+    let k = points.len();
+
+    // The full product polynomial M(x) = Π_j (x - x_j), ascending-degree coefficients.
+    let mut full = vec![Fr::one()];
+    for &(x_j, _) in points {
+        full = multiply_by_linear_factor(&full, &x_j);
+    }
+
+    let mut result = vec![Fr::zero(); k];
+    for &(x_i, y_i) in points {
+        // numerator_i(x) = M(x) / (x - x_i), which is Π_{j != i} (x - x_j).
+        let numerator = divide_by_linear_factor(&full, &x_i);
+        // denom_i = Π_{j != i} (x_i - x_j) = numerator_i(x_i).
+        let denom = evaluate(&numerator, &x_i);
+        let scale = y_i.mul(&denom.inverse());
+        for (coeff, term) in result.iter_mut().zip(numerator.iter()) {
+            *coeff = coeff.add(&scale.mul(term));
+        }
+    }
+    result
+}
+
+/// Multiplies `poly` (ascending-degree coefficients) by `(x - root)`.
+fn multiply_by_linear_factor(poly: &[Fr], root: &Fr) -> Vec<Fr> {
+    let mut result = vec![Fr::zero(); poly.len() + 1];
+    for (i, coeff) in poly.iter().enumerate() {
+        result[i + 1] = result[i + 1].add(coeff);
+        result[i] = result[i].sub(&coeff.mul(root));
+    }
+    result
+}
+
+/// Synthetic division of `poly` (ascending-degree coefficients) by `(x - root)`, assuming
+/// `poly(root) == 0` (i.e. `root` is an exact root, so the remainder is discarded).
+fn divide_by_linear_factor(poly: &[Fr], root: &Fr) -> Vec<Fr> {
+    let descending: Vec<Fr> = poly.iter().rev().copied().collect();
+    let n = descending.len();
+
+    let mut quotient_descending = Vec::with_capacity(n - 1);
+    let mut carry = descending[0];
+    quotient_descending.push(carry);
+    for coeff in &descending[1..n - 1] {
+        carry = coeff.add(&carry.mul(root));
+        quotient_descending.push(carry);
+    }
+
+    quotient_descending.into_iter().rev().collect()
+}
+
+/// Evaluates `poly` (ascending-degree coefficients) at `x` via Horner's method.
+fn evaluate(poly: &[Fr], x: &Fr) -> Fr {
+    let mut result = Fr::zero();
+    for coeff in poly.iter().rev() {
+        result = result.mul(x).add(coeff);
+    }
+    result
+}
@@ -0,0 +1,205 @@
+//! Safe Schnorr signature API over the Grumpkin-curve `schnorr_*` bindings, including the
+//! multisig (MuSig-style) signing protocol used to produce a single aggregate signature from
+//! several participants.
+//!
+//! A combined multisig signature verifies under the aggregated public key with the same
+//! [`schnorr_verify_signature`] used for an ordinary single-signer signature, so an on-chain
+//! verifier doesn't need to know or care how many parties contributed to it.
+//!
+//! This mirrors the [`crate::barretenberg_api::ecdsa`] module's shape (32-byte secret key,
+//! arbitrary-length message, 64-byte signature) and is exercised by the same kind of test
+//! matrix in `tests/schnorr_tests.rs`: key generation, sign/verify round-trip, wrong-key
+//! rejection, and empty/long messages.
+
+use crate::barretenberg_api::bindgen;
+use crate::barretenberg_api::models::{Fr, Point};
+use crate::barretenberg_api::utils::{DeserializeBuffer, SerializeBuffer};
+
+/// A participant's multisig public key, as produced by
+/// [`schnorr_multisig_create_multisig_public_key`].
+pub type MultisigPublicKey = [u8; 128];
+
+/// The public half of a signer's round-1 nonce commitment: the two points `R` and `S`.
+pub type RoundOnePublicOutput = [u8; 128];
+
+/// The private half of a signer's round-1 nonce commitment: the two scalar nonces behind `R`
+/// and `S`.
+pub type RoundOnePrivateOutput = [u8; 64];
+
+/// A signer's round-2 signature share.
+pub type RoundTwoOutput = [u8; 32];
+
+/// Computes the Grumpkin public key for a Schnorr private key.
+pub unsafe fn schnorr_compute_public_key(private_key: &Fr) -> Point {
+    let mut output: <Point as DeserializeBuffer>::Slice = [0; 64];
+    bindgen::schnorr_compute_public_key(private_key.data.as_ptr(), output.as_mut_ptr());
+    Point::from_buffer(output)
+}
+
+/// Signs `message` with `private_key`, returning the `(s, e)` signature components.
+pub unsafe fn schnorr_construct_signature(
+    message: &[u8],
+    private_key: &Fr,
+) -> ([u8; 32], [u8; 32]) {
+    let mut sig_s = [0u8; 32];
+    let mut sig_e = [0u8; 32];
+    bindgen::schnorr_construct_signature(
+        message.as_ptr(),
+        message.len(),
+        private_key.data.as_ptr(),
+        sig_s.as_mut_ptr(),
+        sig_e.as_mut_ptr(),
+    );
+    (sig_s, sig_e)
+}
+
+/// Verifies a `(s, e)` signature over `message` under `public_key`.
+pub unsafe fn schnorr_verify_signature(
+    message: &[u8],
+    public_key: &Point,
+    sig_s: &mut [u8; 32],
+    sig_e: &mut [u8; 32],
+) -> bool {
+    let pubkey_buf = public_key.to_buffer();
+    bindgen::schnorr_verify_signature(
+        message.as_ptr(),
+        message.len(),
+        pubkey_buf.as_ptr(),
+        sig_s.as_mut_ptr(),
+        sig_e.as_mut_ptr(),
+    )
+}
+
+/// Derives a participant's multisig public key from their Schnorr private key.
+pub unsafe fn schnorr_multisig_create_multisig_public_key(private_key: &Fr) -> MultisigPublicKey {
+    let mut output = [0u8; 128];
+    bindgen::schnorr_multisig_create_multisig_public_key(
+        private_key.data.as_ptr(),
+        output.as_mut_ptr(),
+    );
+    output
+}
+
+fn length_prefixed(chunks: &[&[u8]], chunk_len: usize) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(4 + chunks.len() * chunk_len);
+    buf.extend_from_slice(&(chunks.len() as u32).to_be_bytes());
+    for chunk in chunks {
+        buf.extend_from_slice(chunk);
+    }
+    buf
+}
+
+/// Errors from the multisig signing protocol.
+#[derive(Debug, thiserror::Error)]
+pub enum MultisigError {
+    #[error("one or more signer public keys failed validation")]
+    InvalidPublicKeys,
+}
+
+/// Combines the participants' multisig public keys into the aggregate public key the joint
+/// signature will verify under.
+pub fn aggregate_public_keys(pubkeys: &[MultisigPublicKey]) -> Result<Point, MultisigError> {
+    let keys: Vec<&[u8]> = pubkeys.iter().map(|key| key.as_slice()).collect();
+    let buf = length_prefixed(&keys, 128);
+
+    let mut output: <Point as DeserializeBuffer>::Slice = [0; 64];
+    let mut success = false;
+    unsafe {
+        bindgen::schnorr_multisig_validate_and_combine_signer_pubkeys(
+            buf.as_ptr(),
+            output.as_mut_ptr(),
+            &mut success,
+        );
+    }
+    if success {
+        Ok(Point::from_buffer(output))
+    } else {
+        Err(MultisigError::InvalidPublicKeys)
+    }
+}
+
+/// Generates a signer's round-1 nonce commitment: a public `(R, S)` to publish, and the private
+/// nonces behind it to keep for round 2.
+pub fn construct_signature_round_1() -> (RoundOnePublicOutput, RoundOnePrivateOutput) {
+    let mut public_output = [0u8; 128];
+    let mut private_output = [0u8; 64];
+    unsafe {
+        bindgen::schnorr_multisig_construct_signature_round_1(
+            public_output.as_mut_ptr(),
+            private_output.as_mut_ptr(),
+        );
+    }
+    (public_output, private_output)
+}
+
+/// Produces this signer's round-2 signature share over `message`, given their private key,
+/// their own round-1 private nonces, and every signer's round-1 public commitment (in signing
+/// order).
+pub fn construct_signature_round_2(
+    message: &[u8],
+    private_key: &Fr,
+    round1_private: &RoundOnePrivateOutput,
+    round1_public_from_all_signers: &[RoundOnePublicOutput],
+) -> RoundTwoOutput {
+    let round1_public: Vec<&[u8]> = round1_public_from_all_signers
+        .iter()
+        .map(|output| output.as_slice())
+        .collect();
+    let round1_public_buf = length_prefixed(&round1_public, 128);
+
+    let mut round2_output = [0u8; 32];
+    unsafe {
+        bindgen::schnorr_multisig_construct_signature_round_2(
+            message.as_ptr(),
+            message.len(),
+            private_key.data.as_ptr(),
+            round1_private.as_ptr(),
+            round1_public_buf.as_ptr(),
+            round2_output.as_mut_ptr(),
+        );
+    }
+    round2_output
+}
+
+/// Combines every signer's round-2 share into the final `(s, e)` signature. Returns `None` if
+/// any signer's commitments don't combine into a valid signature (e.g. a mismatched round-1
+/// commitment).
+pub fn combine_signatures(
+    message: &[u8],
+    pubkeys: &[MultisigPublicKey],
+    round1_public_from_all_signers: &[RoundOnePublicOutput],
+    round2_outputs: &[RoundTwoOutput],
+) -> Option<([u8; 32], [u8; 32])> {
+    let keys: Vec<&[u8]> = pubkeys.iter().map(|key| key.as_slice()).collect();
+    let pubkey_buf = length_prefixed(&keys, 128);
+
+    let round1_public: Vec<&[u8]> = round1_public_from_all_signers
+        .iter()
+        .map(|output| output.as_slice())
+        .collect();
+    let round1_public_buf = length_prefixed(&round1_public, 128);
+
+    let round2: Vec<&[u8]> = round2_outputs.iter().map(|output| output.as_slice()).collect();
+    let round2_buf = length_prefixed(&round2, 32);
+
+    let mut sig_s = [0u8; 32];
+    let mut sig_e = [0u8; 32];
+    let mut success = false;
+    unsafe {
+        bindgen::schnorr_multisig_combine_signatures(
+            message.as_ptr(),
+            message.len(),
+            pubkey_buf.as_ptr(),
+            round1_public_buf.as_ptr(),
+            round2_buf.as_ptr(),
+            sig_s.as_mut_ptr(),
+            sig_e.as_mut_ptr(),
+            &mut success,
+        );
+    }
+    if success {
+        Some((sig_s, sig_e))
+    } else {
+        None
+    }
+}
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use crate::barretenberg_api::aes::{aes_encrypt_buffer_cbc, aes_decrypt_buffer_cbc};
+    use crate::barretenberg_api::aes::{
+        aes_decrypt_buffer_cbc, aes_decrypt_cbc_pkcs7, aes_decrypt_ctr, aes_encrypt_buffer_cbc,
+        aes_encrypt_cbc_pkcs7, aes_encrypt_ctr, AesError,
+    };
     use crate::barretenberg_api::bindgen;
 
     // Initialize the slab allocator before running AES tests
@@ -177,4 +180,72 @@ mod tests {
         
         assert!(result.is_err(), "Function should panic with unpadded input");
     }
+
+    #[test]
+    fn test_aes_cbc_pkcs7_roundtrip_unpadded_input() {
+        let plaintext = b"not a multiple of 16 bytes at all";
+        let key = [0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
+                   0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c];
+        let iv = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+                  0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f];
+
+        unsafe {
+            let ciphertext = aes_encrypt_cbc_pkcs7(plaintext, &iv, &key);
+            assert_eq!(ciphertext.as_slice().len() % 16, 0);
+
+            let decrypted = aes_decrypt_cbc_pkcs7(ciphertext.as_slice(), &iv, &key)
+                .expect("valid padding should decrypt");
+            assert_eq!(decrypted.as_slice(), plaintext);
+        }
+    }
+
+    #[test]
+    fn test_aes_cbc_pkcs7_rejects_invalid_padding() {
+        let key = [0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
+                   0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c];
+        let iv = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+                  0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f];
+
+        // A ciphertext block that decrypts to essentially random bytes, vanishingly unlikely to
+        // end in valid PKCS#7 padding.
+        let bogus_ciphertext = [0x42u8; 16];
+
+        unsafe {
+            let result = aes_decrypt_cbc_pkcs7(&bogus_ciphertext, &iv, &key);
+            assert!(matches!(result, Err(AesError::InvalidPadding)));
+        }
+    }
+
+    #[test]
+    fn test_aes_ctr_roundtrip_arbitrary_length() {
+        let plaintext = b"CTR mode needs no padding at all, any length works fine.";
+        let key = [0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
+                   0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c];
+        let iv = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+                  0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f];
+
+        unsafe {
+            let ciphertext = aes_encrypt_ctr(plaintext, &iv, &key);
+            assert_eq!(ciphertext.len(), plaintext.len());
+            assert_ne!(ciphertext, plaintext);
+
+            let decrypted = aes_decrypt_ctr(&ciphertext, &iv, &key);
+            assert_eq!(decrypted, plaintext);
+        }
+    }
+
+    #[test]
+    fn test_aes_ctr_encrypt_is_its_own_inverse() {
+        let plaintext = b"same function decrypts and encrypts in CTR mode";
+        let key = [0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
+                   0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c];
+        let iv = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+                  0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f];
+
+        unsafe {
+            let ciphertext = aes_encrypt_ctr(plaintext, &iv, &key);
+            let roundtrip = aes_encrypt_ctr(&ciphertext, &iv, &key);
+            assert_eq!(roundtrip, plaintext);
+        }
+    }
 }
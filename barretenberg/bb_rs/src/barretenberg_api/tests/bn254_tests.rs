@@ -1,8 +1,42 @@
 #[cfg(test)]
 mod tests {
-    use crate::barretenberg_api::bn254::bn254_fr_sqrt;
+    use crate::barretenberg_api::bn254::{bn254_fr_sqrt, bn254_fr_sqrt_reference};
     use crate::barretenberg_api::models::Fr;
 
+    /// Asserts that the FFI and pure-Rust roots of `input` agree up to sign, and that the
+    /// pure-Rust root actually squares back to `input`.
+    fn assert_roots_agree(input: &Fr) {
+        let ffi_result = unsafe { bn254_fr_sqrt(input) };
+        let rust_result = bn254_fr_sqrt_reference(input);
+        assert_eq!(
+            ffi_result.is_some(),
+            rust_result.is_some(),
+            "FFI and pure-Rust implementations disagree on whether a square root exists"
+        );
+
+        if let (Some(ffi_root), Some(rust_root)) = (ffi_result, rust_result) {
+            assert_eq!(
+                rust_root.mul(&rust_root),
+                *input,
+                "pure-Rust sqrt({:?}) squared should equal the input",
+                input.data
+            );
+
+            let modulus = num_bigint::BigUint::from_bytes_be(&Fr::MODULUS);
+            let rust_value = num_bigint::BigUint::from_bytes_be(&rust_root.data);
+            let negated_bytes = (modulus - rust_value).to_bytes_be();
+            let mut negated_data = [0u8; 32];
+            negated_data[32 - negated_bytes.len()..].copy_from_slice(&negated_bytes);
+            let negated_rust_root = Fr { data: negated_data };
+
+            assert!(
+                ffi_root == rust_root || ffi_root == negated_rust_root,
+                "FFI and pure-Rust roots of {:?} don't agree up to sign",
+                input.data
+            );
+        }
+    }
+
     // cargo test bn254_tests -v -- --test-threads=1 --nocapture
 
     #[test]
@@ -71,18 +105,19 @@ mod tests {
 
     #[test]
     fn test_bn254_fr_sqrt_non_square() {
-        // Test with a value that is likely not a perfect square
-        // 2 is not a quadratic residue in many fields
+        // 2 is a quadratic residue in BN254's Fr field (the modulus is 1 mod 8), so the non-square
+        // case uses 5, a generator of Fr's multiplicative group and therefore a non-residue.
         let mut two_data = [0u8; 32];
         two_data[31] = 2;
         let two = Fr { data: two_data };
-        
-        unsafe {
-            let result = bn254_fr_sqrt(&two);
-            // For bn254 Fr field, 2 may or may not be a quadratic residue
-            // Just verify the function returns a valid result (Some or None)
-            println!("Square root of 2 exists: {}", result.is_some());
-        }
+        let five = Fr::from_u64(5);
+
+        assert_roots_agree(&two);
+        assert_roots_agree(&five);
+        assert!(
+            bn254_fr_sqrt_reference(&five).is_none(),
+            "5 is not a quadratic residue, so it should have no square root"
+        );
     }
 
     #[test]
@@ -94,17 +129,8 @@ mod tests {
         large_data[30] = 0x45;
         large_data[31] = 0x67;
         let large = Fr { data: large_data };
-        
-        unsafe {
-            let result = bn254_fr_sqrt(&large);
-            // Just verify the function executes without panicking
-            println!("Square root of large value exists: {}", result.is_some());
-            
-            if let Some(sqrt) = result {
-                // Verify the result is non-zero
-                assert_ne!(sqrt.data, [0u8; 32], "Square root of non-zero should be non-zero");
-            }
-        }
+
+        assert_roots_agree(&large);
     }
 
     #[test]
@@ -112,20 +138,45 @@ mod tests {
         // Test that if sqrt(x) = y, then y^2 should equal x (mod p)
         // We'll test with known perfect squares
         let test_cases = [1u8, 4, 9, 16, 25, 36, 49, 64, 81, 100];
-        
+
         for &val in &test_cases {
             let mut data = [0u8; 32];
             data[31] = val;
             let input = Fr { data };
-            
-            unsafe {
-                let result = bn254_fr_sqrt(&input);
-                assert!(result.is_some(), "Square root of {} should exist", val);
-                
-                // Note: We can't easily verify y^2 = x without implementing field multiplication
-                // in this test, but we can at least verify we get a result
-                println!("sqrt({}) exists", val);
-            }
+
+            assert_roots_agree(&input);
+        }
+    }
+
+    #[test]
+    fn test_bn254_fr_sqrt_reference_matches_ffi_for_random_values() {
+        // Non-trivial (non-tiny-perfect-square) inputs, spanning both residues and non-residues,
+        // cross-checking the pure-Rust reference implementation against the FFI.
+        let samples: [[u8; 32]; 5] = [
+            [0xab; 32],
+            [0x01; 32],
+            {
+                let mut data = [0u8; 32];
+                data[0] = 0x2a;
+                data[31] = 0xf3;
+                data
+            },
+            {
+                let mut data = [0u8; 32];
+                data[10] = 0x7c;
+                data[20] = 0x19;
+                data
+            },
+            {
+                let mut data = [0u8; 32];
+                data[31] = 123;
+                data
+            },
+        ];
+
+        for data in samples {
+            let input = Fr::from_be_bytes_reduce(&data);
+            assert_roots_agree(&input);
         }
     }
 } 
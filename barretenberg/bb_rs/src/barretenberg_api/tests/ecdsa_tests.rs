@@ -4,9 +4,25 @@ mod tests {
         ecdsa__compute_public_key, ecdsa__construct_signature_, ecdsa__verify_signature_,
         ecdsa__recover_public_key_from_signature_,
         ecdsa_r_compute_public_key, ecdsa_r_construct_signature_, ecdsa_r_verify_signature_,
-        ecdsa_r_recover_public_key_from_signature_
+        ecdsa_r_recover_public_key_from_signature_,
+        ecdsa__normalize_signature_s, ecdsa__verify_signature_strict,
+        ecdsa__serialize_public_key_compressed, ecdsa__parse_public_key,
+        ecdsa_r_serialize_public_key_compressed, ecdsa_r_parse_public_key,
+        ecrecover, rfc6979_nonce, Curve, Capability, EcdsaContext,
     };
 
+    /// Computes the Ethereum address for a 64-byte uncompressed public key the same way
+    /// [`ecrecover`] does, so tests can check its output independently of the recovery itself.
+    fn address_from_public_key(public_key: &[u8; 64]) -> [u8; 20] {
+        let mut hasher = tiny_keccak::Keccak::v256();
+        let mut digest = [0u8; 32];
+        tiny_keccak::Hasher::update(&mut hasher, public_key);
+        tiny_keccak::Hasher::finalize(hasher, &mut digest);
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&digest[12..]);
+        address
+    }
+
     // ECDSA secp256k1 tests
     #[test]
     fn test_ecdsa_secp256k1_key_generation() {
@@ -55,9 +71,10 @@ mod tests {
             let recovered_public_key = ecdsa__recover_public_key_from_signature_(
                 message, &sig_r, &sig_s, &mut sig_v
             );
-            
-            // Note: Recovery might not be exact due to the nature of ECDSA recovery
-            assert_ne!(recovered_public_key, [0u8; 64]);
+
+            // The explicit recovery id supplied alongside the signature pins down the unique
+            // public key, so recovery round-trips exactly back to compute_public_key's output.
+            assert_eq!(recovered_public_key, expected_public_key);
         }
     }
 
@@ -95,8 +112,8 @@ mod tests {
             let recovered_public_key = ecdsa_r_recover_public_key_from_signature_(
                 message, &sig_r, &sig_s, &mut sig_v
             );
-            
-            assert_ne!(recovered_public_key, [0u8; 64]);
+
+            assert_eq!(recovered_public_key, expected_public_key);
         }
     }
 
@@ -299,4 +316,290 @@ mod tests {
             assert!(!is_valid_wrong);
         }
     }
-} 
+
+    // RFC 6979 deterministic nonce tests
+    #[test]
+    fn test_rfc6979_nonce_is_deterministic() {
+        let private_key = [20u8; 32];
+        let h1 = [21u8; 32];
+
+        let k1 = rfc6979_nonce(&private_key, &h1, Curve::Secp256k1);
+        let k2 = rfc6979_nonce(&private_key, &h1, Curve::Secp256k1);
+
+        assert_eq!(k1, k2);
+    }
+
+    #[test]
+    fn test_rfc6979_nonce_in_range() {
+        let private_key = [22u8; 32];
+        let h1 = [23u8; 32];
+
+        let k = rfc6979_nonce(&private_key, &h1, Curve::Secp256k1);
+        let n = num_bigint::BigUint::from_bytes_be(&[
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xfe, 0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c,
+            0xd0, 0x36, 0x41, 0x41,
+        ]);
+
+        let k_int = num_bigint::BigUint::from_bytes_be(&k);
+        assert!(k_int >= num_bigint::BigUint::from(1u32));
+        assert!(k_int < n);
+    }
+
+    #[test]
+    fn test_rfc6979_nonce_differs_for_different_messages() {
+        let private_key = [24u8; 32];
+        let h1 = [25u8; 32];
+        let h2 = [26u8; 32];
+
+        let k1 = rfc6979_nonce(&private_key, &h1, Curve::Secp256k1);
+        let k2 = rfc6979_nonce(&private_key, &h2, Curve::Secp256k1);
+
+        assert_ne!(k1, k2);
+    }
+
+    #[test]
+    fn test_rfc6979_nonce_differs_across_curves() {
+        let private_key = [27u8; 32];
+        let h1 = [28u8; 32];
+
+        let k_k1 = rfc6979_nonce(&private_key, &h1, Curve::Secp256k1);
+        let k_r1 = rfc6979_nonce(&private_key, &h1, Curve::Secp256r1);
+
+        assert_ne!(k_k1, k_r1);
+    }
+
+    // Low-S normalization / strict verification tests
+    #[test]
+    fn test_ecdsa_normalize_and_strict_verify_reject_high_s() {
+        let private_key = [30u8; 32];
+        let message = b"low-S normalization test";
+
+        let secp256k1_order = num_bigint::BigUint::from_bytes_be(&[
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xfe, 0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c,
+            0xd0, 0x36, 0x41, 0x41,
+        ]);
+
+        unsafe {
+            let public_key = ecdsa__compute_public_key(&private_key);
+            let (sig_r, sig_s, sig_v) = ecdsa__construct_signature_(message, &private_key);
+
+            let (low_s, flipped) = ecdsa__normalize_signature_s(&sig_s);
+            let low_v = if flipped { sig_v ^ 1 } else { sig_v };
+
+            // Forge the complementary high-S signature: n - low_s, with the opposite parity bit.
+            let s_int = secp256k1_order.clone() - num_bigint::BigUint::from_bytes_be(&low_s);
+            let mut high_s = [0u8; 32];
+            let s_bytes = s_int.to_bytes_be();
+            high_s[32 - s_bytes.len()..].copy_from_slice(&s_bytes);
+            let high_v = low_v ^ 1;
+
+            assert!(ecdsa__verify_signature_strict(message, &public_key, &sig_r, &low_s, &low_v));
+            assert!(!ecdsa__verify_signature_strict(message, &public_key, &sig_r, &high_s, &high_v));
+
+            // The regular (non-strict) verifier still accepts the malleable high-S form.
+            assert!(ecdsa__verify_signature_(message, &public_key, &sig_r, &high_s, &high_v));
+
+            // Normalizing either form of the signature produces an identical canonical signature.
+            let (renormalized_low, flipped_low) = ecdsa__normalize_signature_s(&low_s);
+            let (renormalized_high, flipped_high) = ecdsa__normalize_signature_s(&high_s);
+            assert!(!flipped_low);
+            assert!(flipped_high);
+            assert_eq!(renormalized_low, renormalized_high);
+        }
+    }
+
+    // Compressed public-key encoding tests
+    #[test]
+    fn test_ecdsa_secp256k1_compressed_public_key_round_trip() {
+        let private_key = [31u8; 32];
+
+        unsafe {
+            let public_key = ecdsa__compute_public_key(&private_key);
+            let compressed = ecdsa__serialize_public_key_compressed(&public_key);
+            assert!(compressed[0] == 0x02 || compressed[0] == 0x03);
+
+            let parsed = ecdsa__parse_public_key(&compressed).unwrap();
+            assert_eq!(parsed, public_key);
+
+            let mut uncompressed = [0u8; 65];
+            uncompressed[0] = 0x04;
+            uncompressed[1..].copy_from_slice(&public_key);
+            let parsed_uncompressed = ecdsa__parse_public_key(&uncompressed).unwrap();
+            assert_eq!(parsed_uncompressed, public_key);
+        }
+    }
+
+    #[test]
+    fn test_ecdsa_secp256r1_compressed_public_key_round_trip() {
+        let private_key = [32u8; 32];
+
+        unsafe {
+            let public_key = ecdsa_r_compute_public_key(&private_key);
+            let compressed = ecdsa_r_serialize_public_key_compressed(&public_key);
+            assert!(compressed[0] == 0x02 || compressed[0] == 0x03);
+
+            let parsed = ecdsa_r_parse_public_key(&compressed).unwrap();
+            assert_eq!(parsed, public_key);
+        }
+    }
+
+    #[test]
+    fn test_ecdsa_parse_public_key_rejects_bad_length() {
+        let bytes = [0u8; 10];
+        assert!(ecdsa__parse_public_key(&bytes).is_err());
+    }
+
+    // EcdsaContext tests
+    #[test]
+    fn test_ecdsa_context_all_can_sign_and_verify() {
+        let ctx = EcdsaContext::new(Curve::Secp256k1, Capability::All);
+        let private_key = [33u8; 32];
+        let message = b"context sign/verify test";
+
+        let public_key = ctx.compute_public_key(&private_key);
+        let signature = ctx.sign(message, &private_key).unwrap();
+        assert!(ctx.verify(message, &public_key, &signature).unwrap());
+    }
+
+    #[test]
+    fn test_ecdsa_context_sign_only_refuses_to_verify() {
+        let ctx = EcdsaContext::new(Curve::Secp256k1, Capability::Sign);
+        let private_key = [34u8; 32];
+        let message = b"sign-only context";
+
+        let public_key = ctx.compute_public_key(&private_key);
+        let signature = ctx.sign(message, &private_key).unwrap();
+        assert!(ctx.verify(message, &public_key, &signature).is_err());
+    }
+
+    #[test]
+    fn test_ecdsa_context_verify_only_refuses_to_sign() {
+        let ctx = EcdsaContext::new(Curve::Secp256k1, Capability::Verify);
+        let private_key = [35u8; 32];
+        let message = b"verify-only context";
+
+        assert!(ctx.sign(message, &private_key).is_err());
+    }
+
+    #[test]
+    fn test_ecdsa_context_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<EcdsaContext>();
+    }
+
+    // `ecrecover` tests
+    #[test]
+    fn test_ecrecover_round_trip() {
+        let private_key = [40u8; 32];
+        let hash = [41u8; 32];
+
+        unsafe {
+            let public_key = ecdsa__compute_public_key(&private_key);
+            let (sig_r, sig_s, sig_v) = ecdsa__construct_signature_(&hash, &private_key);
+
+            let mut signature = [0u8; 65];
+            signature[..32].copy_from_slice(&sig_r);
+            signature[32..64].copy_from_slice(&sig_s);
+            signature[64] = sig_v + 27;
+
+            let address = ecrecover(&hash, &signature).expect("ecrecover should succeed");
+            assert_eq!(address, address_from_public_key(&public_key));
+        }
+    }
+
+    #[test]
+    fn test_ecrecover_accepts_0_1_recovery_byte() {
+        let private_key = [42u8; 32];
+        let hash = [43u8; 32];
+
+        unsafe {
+            let public_key = ecdsa__compute_public_key(&private_key);
+            let (sig_r, sig_s, sig_v) = ecdsa__construct_signature_(&hash, &private_key);
+
+            let mut signature = [0u8; 65];
+            signature[..32].copy_from_slice(&sig_r);
+            signature[32..64].copy_from_slice(&sig_s);
+            signature[64] = sig_v;
+
+            let address = ecrecover(&hash, &signature).expect("ecrecover should succeed");
+            assert_eq!(address, address_from_public_key(&public_key));
+        }
+    }
+
+    #[test]
+    fn test_ecrecover_rejects_bad_recovery_byte() {
+        let private_key = [44u8; 32];
+        let hash = [45u8; 32];
+
+        unsafe {
+            let (sig_r, sig_s, _sig_v) = ecdsa__construct_signature_(&hash, &private_key);
+
+            let mut signature = [0u8; 65];
+            signature[..32].copy_from_slice(&sig_r);
+            signature[32..64].copy_from_slice(&sig_s);
+            signature[64] = 4;
+
+            assert!(ecrecover(&hash, &signature).is_err());
+        }
+    }
+
+    #[test]
+    fn test_ecrecover_rejects_high_s() {
+        let private_key = [46u8; 32];
+        let hash = [47u8; 32];
+
+        let secp256k1_order = num_bigint::BigUint::from_bytes_be(&[
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xfe, 0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c,
+            0xd0, 0x36, 0x41, 0x41,
+        ]);
+
+        unsafe {
+            let (sig_r, sig_s, sig_v) = ecdsa__construct_signature_(&hash, &private_key);
+            let (low_s, flipped) = ecdsa__normalize_signature_s(&sig_s);
+            let low_v = if flipped { sig_v ^ 1 } else { sig_v };
+
+            // Forge the complementary high-S form: n - low_s, with the opposite parity bit.
+            let s_int = secp256k1_order - num_bigint::BigUint::from_bytes_be(&low_s);
+            let mut high_s = [0u8; 32];
+            let s_bytes = s_int.to_bytes_be();
+            high_s[32 - s_bytes.len()..].copy_from_slice(&s_bytes);
+            let high_v = low_v ^ 1;
+
+            let mut signature = [0u8; 65];
+            signature[..32].copy_from_slice(&sig_r);
+            signature[32..64].copy_from_slice(&high_s);
+            signature[64] = high_v + 27;
+
+            assert!(ecrecover(&hash, &signature).is_err());
+        }
+    }
+
+    #[test]
+    fn test_ecrecover_rejects_zero_r() {
+        let hash = [48u8; 32];
+        let mut signature = [0u8; 65];
+        signature[64] = 27;
+
+        assert!(ecrecover(&hash, &signature).is_err());
+    }
+
+    #[test]
+    fn test_ecrecover_rejects_r_at_or_above_order() {
+        let hash = [49u8; 32];
+        let secp256k1_order = [
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xfe, 0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c,
+            0xd0, 0x36, 0x41, 0x41,
+        ];
+
+        let mut signature = [0u8; 65];
+        signature[..32].copy_from_slice(&secp256k1_order);
+        signature[32] = 1;
+        signature[64] = 27;
+
+        assert!(ecrecover(&hash, &signature).is_err());
+    }
+}
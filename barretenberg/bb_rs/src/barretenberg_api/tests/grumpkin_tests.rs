@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use crate::barretenberg_api::grumpkin::{ecc_grumpkin__mul, ecc_grumpkin__add, ecc_grumpkin__batch_mul, ecc_grumpkin__get_random_scalar_mod_circuit_modulus, ecc_grumpkin__reduce512_buffer_mod_circuit_modulus};
+    use crate::barretenberg_api::grumpkin::{ecc_grumpkin__mul, ecc_grumpkin__add, ecc_grumpkin__batch_mul, ecc_grumpkin__msm, ecc_grumpkin__get_random_scalar_mod_circuit_modulus, ecc_grumpkin__reduce512_buffer_mod_circuit_modulus, WnafTable};
     use crate::barretenberg_api::models::{Fr, Point};
 
     // cargo test grumpkin_tests -v -- --test-threads=1 --nocapture
@@ -99,6 +99,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_grumpkin_msm_vs_individual_muls_and_adds() {
+        let points = vec![
+            Point {
+                x: Fr { data: [1u8; 32] },
+                y: Fr { data: [2u8; 32] },
+            },
+            Point {
+                x: Fr { data: [3u8; 32] },
+                y: Fr { data: [4u8; 32] },
+            },
+            Point {
+                x: Fr { data: [5u8; 32] },
+                y: Fr { data: [6u8; 32] },
+            },
+        ];
+        let scalars = vec![
+            Fr { data: [7u8; 32] },
+            Fr { data: [9u8; 32] },
+            Fr { data: [11u8; 32] },
+        ];
+
+        unsafe {
+            let msm_result = ecc_grumpkin__msm(&points, &scalars);
+
+            // The naive definition of an MSM: multiply each point by its own scalar, then sum.
+            let mut expected = ecc_grumpkin__mul(&points[0], &scalars[0]);
+            for (point, scalar) in points.iter().zip(scalars.iter()).skip(1) {
+                expected = ecc_grumpkin__add(&expected, &ecc_grumpkin__mul(point, scalar));
+            }
+
+            assert_eq!(msm_result.x.data, expected.x.data);
+            assert_eq!(msm_result.y.data, expected.y.data);
+        }
+    }
+
+    #[test]
+    fn test_grumpkin_msm_empty_is_point_at_infinity() {
+        unsafe {
+            let result = ecc_grumpkin__msm(&[], &[]);
+            assert_eq!(result.x.data, [0u8; 32]);
+            assert_eq!(result.y.data, [0u8; 32]);
+        }
+    }
+
     // JavaScript/WASM compatibility tests
     // These tests verify that the Rust implementation produces identical results to the JS/WASM version
 
@@ -289,4 +334,59 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_wnaf_table_matches_plain_mul() {
+        let point = Point {
+            x: Fr { data: [1u8; 32] },
+            y: Fr { data: [2u8; 32] },
+        };
+
+        unsafe {
+            let table = WnafTable::precompute(&point, 4);
+
+            for scalar_byte in [1u8, 2, 3, 7, 9, 255] {
+                let scalar = Fr { data: [scalar_byte; 32] };
+
+                let expected = ecc_grumpkin__mul(&point, &scalar);
+                let actual = table.mul(&scalar);
+
+                assert_eq!(actual.x.data, expected.x.data, "mismatch for scalar {}", scalar_byte);
+                assert_eq!(actual.y.data, expected.y.data, "mismatch for scalar {}", scalar_byte);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wnaf_table_zero_scalar_is_point_at_infinity() {
+        let point = Point {
+            x: Fr { data: [5u8; 32] },
+            y: Fr { data: [6u8; 32] },
+        };
+
+        unsafe {
+            let table = WnafTable::precompute(&point, 3);
+            let result = table.mul(&Fr { data: [0u8; 32] });
+
+            assert_eq!(result.x.data, [0u8; 32]);
+            assert_eq!(result.y.data, [0u8; 32]);
+        }
+    }
+
+    #[test]
+    fn test_wnaf_table_agrees_across_window_widths() {
+        let point = Point {
+            x: Fr { data: [3u8; 32] },
+            y: Fr { data: [4u8; 32] },
+        };
+        let scalar = Fr { data: [0x7fu8; 32] };
+
+        unsafe {
+            let narrow = WnafTable::precompute(&point, 2);
+            let wide = WnafTable::precompute(&point, 6);
+
+            assert_eq!(narrow.mul(&scalar).x.data, wide.mul(&scalar).x.data);
+            assert_eq!(narrow.mul(&scalar).y.data, wide.mul(&scalar).y.data);
+        }
+    }
 } 
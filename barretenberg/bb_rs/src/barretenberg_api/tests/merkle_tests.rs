@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests {
+    use crate::barretenberg_api::merkle::{verify, MerkleHasher, MerkleTree};
+    use crate::barretenberg_api::models::Fr;
+    use crate::barretenberg_api::poseidon2::poseidon2_hash;
+
+    // cargo test merkle_tests -v -- --test-threads=1 --nocapture
+
+    #[test]
+    fn test_empty_tree_root_is_all_zero_leaves_folded_up() {
+        let tree = unsafe { MerkleTree::new(3) };
+
+        let mut expected = Fr::from_u64(0);
+        for _ in 0..3 {
+            expected = unsafe { poseidon2_hash(&[expected, expected]) };
+        }
+        assert_eq!(tree.root().data, expected.data);
+    }
+
+    #[test]
+    fn test_push_returns_sequential_indices() {
+        let mut tree = unsafe { MerkleTree::new(3) };
+
+        assert_eq!(unsafe { tree.push(Fr::from_u64(1)) }, 0);
+        assert_eq!(unsafe { tree.push(Fr::from_u64(2)) }, 1);
+        assert_eq!(unsafe { tree.push(Fr::from_u64(3)) }, 2);
+    }
+
+    #[test]
+    fn test_single_leaf_root_matches_manual_folding() {
+        let mut tree = unsafe { MerkleTree::new(2) };
+        let leaf = Fr::from_u64(42);
+        unsafe { tree.push(leaf) };
+
+        let zero = Fr::from_u64(0);
+        let level1 = unsafe { poseidon2_hash(&[leaf, zero]) };
+        let expected_root = unsafe { poseidon2_hash(&[level1, zero]) };
+
+        assert_eq!(tree.root().data, expected_root.data);
+    }
+
+    #[test]
+    fn test_proof_verifies_against_root() {
+        let mut tree = unsafe { MerkleTree::new(3) };
+        let leaves: Vec<Fr> = (1..=5u64).map(Fr::from_u64).collect();
+        for leaf in &leaves {
+            unsafe { tree.push(*leaf) };
+        }
+
+        let root = tree.root();
+        for (index, leaf) in leaves.iter().enumerate() {
+            let path = tree.proof(index as u64);
+            assert!(
+                unsafe { verify(MerkleHasher::Poseidon2, &root, leaf, index as u64, &path) },
+                "proof for leaf {} should verify",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_proof_fails_for_wrong_leaf() {
+        let mut tree = unsafe { MerkleTree::new(3) };
+        for leaf in (1..=4u64).map(Fr::from_u64) {
+            unsafe { tree.push(leaf) };
+        }
+
+        let root = tree.root();
+        let path = tree.proof(0);
+        let wrong_leaf = Fr::from_u64(999);
+
+        assert!(!unsafe { verify(MerkleHasher::Poseidon2, &root, &wrong_leaf, 0, &path) });
+    }
+
+    #[test]
+    fn test_proof_for_unpushed_index_uses_empty_digests() {
+        let mut tree = unsafe { MerkleTree::new(3) };
+        unsafe { tree.push(Fr::from_u64(1)) };
+
+        let root = tree.root();
+        let zero = Fr::from_u64(0);
+        let path = tree.proof(1);
+
+        assert!(unsafe { verify(MerkleHasher::Poseidon2, &root, &zero, 1, &path) });
+    }
+
+    #[test]
+    fn test_pedersen_hasher_produces_different_root_than_poseidon2() {
+        let mut poseidon_tree = unsafe { MerkleTree::new(2) };
+        let mut pedersen_tree =
+            unsafe { MerkleTree::with_hasher(2, MerkleHasher::Pedersen { hash_index: 0 }) };
+
+        for leaf in (1..=3u64).map(Fr::from_u64) {
+            unsafe { poseidon_tree.push(leaf) };
+            unsafe { pedersen_tree.push(leaf) };
+        }
+
+        assert_ne!(poseidon_tree.root().data, pedersen_tree.root().data);
+    }
+
+    #[test]
+    fn test_pedersen_proof_verifies_with_matching_hasher() {
+        let hasher = MerkleHasher::Pedersen { hash_index: 7 };
+        let mut tree = unsafe { MerkleTree::with_hasher(3, hasher) };
+        let leaves: Vec<Fr> = (1..=3u64).map(Fr::from_u64).collect();
+        for leaf in &leaves {
+            unsafe { tree.push(*leaf) };
+        }
+
+        let root = tree.root();
+        let path = tree.proof(1);
+        assert!(unsafe { verify(hasher, &root, &leaves[1], 1, &path) });
+    }
+
+    #[test]
+    #[should_panic(expected = "tree of height 1 is full")]
+    fn test_push_past_capacity_panics() {
+        let mut tree = unsafe { MerkleTree::new(1) };
+        unsafe { tree.push(Fr::from_u64(1)) };
+        unsafe { tree.push(Fr::from_u64(2)) };
+        unsafe { tree.push(Fr::from_u64(3)) };
+    }
+}
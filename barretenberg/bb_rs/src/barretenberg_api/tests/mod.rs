@@ -0,0 +1,14 @@
+mod aes_tests;
+mod bbapi;
+mod bn254_tests;
+mod ecdsa_tests;
+mod grumpkin_tests;
+mod merkle_tests;
+mod models_tests;
+mod pedersen_tests;
+mod poseidon2_tests;
+mod poseidon_schnorr_tests;
+mod reed_solomon_tests;
+mod schnorr_tests;
+mod secp256k1_tests;
+mod vrf_tests;
@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod tests {
+    use crate::barretenberg_api::grumpkin::{
+        ecc_grumpkin__get_random_scalar_mod_circuit_modulus,
+        ecc_grumpkin__reduce512_buffer_mod_circuit_modulus,
+    };
+    use crate::barretenberg_api::models::{Fr, PrimeField};
+
+    #[test]
+    fn test_from_u128_matches_from_u64_for_small_values() {
+        let small = Fr::from_u128(42u128);
+        let expected = Fr::from_u64(42u64);
+        assert_eq!(small.data, expected.data);
+    }
+
+    #[test]
+    fn test_from_dec_str_matches_from_hex() {
+        let from_dec = Fr::from_dec_str("255").unwrap();
+        let from_hex = Fr::from_hex("0xff").unwrap();
+        assert_eq!(from_dec.data, from_hex.data);
+    }
+
+    #[test]
+    fn test_from_dec_str_rejects_garbage() {
+        assert!(Fr::from_dec_str("not a number").is_err());
+    }
+
+    #[test]
+    fn test_to_bytes_be_and_le_are_reverses() {
+        let value = Fr::from_hex("0x0a0000000000000000").unwrap();
+        let be = value.to_bytes_be();
+        let mut le = value.to_bytes_le();
+        le.reverse();
+        assert_eq!(be, le);
+    }
+
+    #[test]
+    fn test_add_sub_roundtrip() {
+        let a = Fr::from_u64(5);
+        let b = Fr::from_u64(3);
+        let sum = a.add(&b);
+        assert_eq!(sum.data, Fr::from_u64(8).data);
+        assert_eq!(sum.sub(&b).data, a.data);
+    }
+
+    #[test]
+    fn test_sub_wraps_below_zero() {
+        let a = Fr::from_u64(1);
+        let b = Fr::from_u64(2);
+        let result = a.sub(&b);
+        // 1 - 2 mod p == p - 1
+        assert_eq!(result.data, Fr::one().neg().data);
+    }
+
+    #[test]
+    fn test_mul_and_neg() {
+        let a = Fr::from_u64(6);
+        let b = Fr::from_u64(7);
+        assert_eq!(a.mul(&b).data, Fr::from_u64(42).data);
+        assert_eq!(a.add(&a.neg()).data, Fr::zero().data);
+    }
+
+    #[test]
+    fn test_pow() {
+        let base = Fr::from_u64(2);
+        assert_eq!(base.pow(10).data, Fr::from_u64(1024).data);
+        assert_eq!(base.pow(0).data, Fr::one().data);
+    }
+
+    #[test]
+    fn test_inverse_roundtrips_to_one() {
+        let value = Fr::from_u64(12345);
+        let inverse = value.inverse();
+        assert_eq!(value.mul(&inverse).data, Fr::one().data);
+    }
+
+    #[test]
+    fn test_inverse_of_zero_is_zero_not_a_panic() {
+        assert_eq!(Fr::zero().inverse().data, Fr::zero().data);
+    }
+
+    #[test]
+    fn test_checked_inverse_of_zero_is_none() {
+        assert!(Fr::zero().checked_inverse().is_none());
+    }
+
+    #[test]
+    fn test_checked_inverse_of_nonzero_is_some() {
+        assert!(Fr::from_u64(7).checked_inverse().is_some());
+    }
+
+    #[test]
+    fn test_prime_field_arithmetic_matches_inherent_methods() {
+        let a = Fr::from_u64(6);
+        let b = Fr::from_u64(7);
+        assert_eq!(PrimeField::add(&a, &b).data, a.add(&b).data);
+        assert_eq!(PrimeField::sub(&a, &b).data, a.sub(&b).data);
+        assert_eq!(PrimeField::mul(&a, &b).data, a.mul(&b).data);
+        assert_eq!(PrimeField::neg(&a).data, a.neg().data);
+        assert_eq!(PrimeField::pow(&a, 3).data, a.pow(3).data);
+        assert_eq!(
+            PrimeField::invert(&a).map(|v| v.data),
+            a.checked_inverse().map(|v| v.data)
+        );
+        assert!(!PrimeField::is_zero(&a));
+        assert!(PrimeField::is_zero(&Fr::zero()));
+    }
+
+    #[test]
+    fn test_to_repr_is_little_endian_and_from_repr_round_trips() {
+        let value = Fr::from_u64(0x0102_0304);
+        let repr = PrimeField::to_repr(&value);
+        assert_eq!(repr, value.to_bytes_le());
+        assert_eq!(<Fr as PrimeField>::from_repr(repr).data, value.data);
+    }
+
+    #[test]
+    fn test_from_repr_reduces_out_of_range_values() {
+        // All-0xff bytes, interpreted little-endian, is far larger than the field modulus.
+        let repr = [0xffu8; 32];
+        let reduced = <Fr as PrimeField>::from_repr(repr);
+        let mut be = repr;
+        be.reverse();
+        assert_eq!(reduced.data, Fr::from_be_bytes_reduce(&be).data);
+    }
+
+    #[test]
+    fn test_bits_are_most_significant_first_and_match_to_bytes_be() {
+        let value = Fr::from_u64(0b1011);
+        let bits: Vec<bool> = PrimeField::bits(&value).collect();
+        assert_eq!(bits.len(), 256);
+        // Only the last 4 bits (the low nibble) should be set, matching 0b1011.
+        assert_eq!(&bits[252..256], &[true, false, true, true]);
+        assert!(bits[..252].iter().all(|&bit| !bit));
+    }
+
+    #[test]
+    fn test_prime_field_round_trips_ffi_random_scalar() {
+        unsafe {
+            let scalar = ecc_grumpkin__get_random_scalar_mod_circuit_modulus();
+            let repr = PrimeField::to_repr(&scalar);
+            assert_eq!(<Fr as PrimeField>::from_repr(repr).data, scalar.data);
+        }
+    }
+
+    #[test]
+    fn test_prime_field_add_matches_ffi_reduce512() {
+        unsafe {
+            let a = ecc_grumpkin__get_random_scalar_mod_circuit_modulus();
+            let b = ecc_grumpkin__get_random_scalar_mod_circuit_modulus();
+
+            let mut wide = [0u8; 64];
+            wide[32..].copy_from_slice(&a.to_bytes_be());
+            let a_mod = ecc_grumpkin__reduce512_buffer_mod_circuit_modulus(&wide);
+            assert_eq!(a_mod.data, a.data, "reduce512 of an already-canonical value is a no-op");
+
+            assert_eq!(PrimeField::add(&a, &b).data, a.add(&b).data);
+        }
+    }
+}
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use crate::barretenberg_api::poseidon2::{poseidon2_hash, poseidon2_hashes, poseidon2_permutation};
+    use crate::barretenberg_api::poseidon2::{
+        poseidon2_hash, poseidon2_hashes, poseidon2_merkle_root, poseidon2_permutation,
+        poseidon2_tree_layer, Poseidon2Sponge,
+    };
     use crate::barretenberg_api::models::Fr;
 
     // cargo test poseidon2_tests -v -- --test-threads=1 --nocapture
@@ -219,20 +222,13 @@ mod tests {
         //   new Fr(0x0810e7e9a1c236aae4ebff7d3751d9f7346dc443d1de863977d2b81fe8c557f4n),
         //   new Fr(0x1f4a188575e29985b6f8ad03afc1f0759488f8835aafb6e19e06160fb64d3d4an),
         // ]
-        let mut inputs = vec![
-            Fr { data: [0u8; 32] },  // 1n
-            Fr { data: [0u8; 32] },  // 2n
-            Fr { data: [0u8; 32] },  // 3n
-            Fr { data: [0u8; 32] },  // 0x0a0000000000000000n
+        let inputs = vec![
+            Fr::from_u64(1),
+            Fr::from_u64(2),
+            Fr::from_u64(3),
+            Fr::from_hex("0x0a0000000000000000").expect("valid hex literal"),
         ];
         
-        // Set the values in big-endian
-        inputs[0].data[31] = 1;  // 1n
-        inputs[1].data[31] = 2;  // 2n
-        inputs[2].data[31] = 3;  // 3n
-        // 0x0a0000000000000000n = 720575940379279360
-        inputs[3].data[23] = 0x0a;  // Set the appropriate bytes for this large number
-        
         let results = unsafe { poseidon2_permutation(&inputs) };
         
         assert_eq!(results.len(), 4);
@@ -260,4 +256,124 @@ mod tests {
         assert_eq!(results[2].data, expected_2);
         assert_eq!(results[3].data, expected_3);
     }
-} 
+
+    #[test]
+    fn test_poseidon2_sponge_matches_hash_single_absorb() {
+        let inputs = vec![
+            Fr { data: [1u8; 32] },
+            Fr { data: [2u8; 32] },
+            Fr { data: [3u8; 32] },
+        ];
+
+        let expected = unsafe { poseidon2_hash(&inputs) };
+
+        let mut sponge = Poseidon2Sponge::new();
+        unsafe { sponge.absorb(&inputs) };
+        let result = unsafe { sponge.finalize() };
+
+        assert_eq!(result.data, expected.data);
+    }
+
+    #[test]
+    fn test_poseidon2_sponge_matches_hash_chunked_absorb() {
+        // The same inputs as above, split across multiple absorb() calls in odd-sized chunks,
+        // should hash to the same value as absorbing them all at once.
+        let inputs = [
+            Fr { data: [1u8; 32] },
+            Fr { data: [2u8; 32] },
+            Fr { data: [3u8; 32] },
+        ];
+
+        let expected = unsafe { poseidon2_hash(&inputs) };
+
+        let mut sponge = Poseidon2Sponge::new();
+        unsafe { sponge.absorb(&inputs[0..1]) };
+        unsafe { sponge.absorb(&inputs[1..3]) };
+        let result = unsafe { sponge.finalize() };
+
+        assert_eq!(result.data, expected.data);
+    }
+
+    #[test]
+    fn test_poseidon2_sponge_spanning_multiple_blocks() {
+        // Five inputs span two rate-3 blocks, exercising the full-block permutation path inside
+        // `absorb` as well as the padded final permutation inside `finalize`.
+        let inputs = vec![
+            Fr { data: [10u8; 32] },
+            Fr { data: [20u8; 32] },
+            Fr { data: [30u8; 32] },
+            Fr { data: [40u8; 32] },
+            Fr { data: [50u8; 32] },
+        ];
+
+        let expected = unsafe { poseidon2_hash(&inputs) };
+
+        let mut sponge = Poseidon2Sponge::new();
+        unsafe { sponge.absorb(&inputs) };
+        let result = unsafe { sponge.finalize() };
+
+        assert_eq!(result.data, expected.data);
+    }
+
+    #[test]
+    fn test_poseidon2_tree_layer_full_groups() {
+        let nodes = vec![
+            Fr { data: [1u8; 32] },
+            Fr { data: [2u8; 32] },
+            Fr { data: [3u8; 32] },
+            Fr { data: [4u8; 32] },
+        ];
+
+        let layer = unsafe { poseidon2_tree_layer(&nodes, 2) };
+
+        let expected = vec![
+            unsafe { poseidon2_hash(&nodes[0..2]) },
+            unsafe { poseidon2_hash(&nodes[2..4]) },
+        ];
+        assert_eq!(layer.len(), expected.len());
+        for (actual, expected) in layer.iter().zip(expected.iter()) {
+            assert_eq!(actual.data, expected.data);
+        }
+    }
+
+    #[test]
+    fn test_poseidon2_tree_layer_pads_final_group() {
+        let nodes = vec![
+            Fr { data: [1u8; 32] },
+            Fr { data: [2u8; 32] },
+            Fr { data: [3u8; 32] },
+        ];
+
+        let layer = unsafe { poseidon2_tree_layer(&nodes, 2) };
+
+        let padded_last = vec![nodes[2], Fr::from_u64(0)];
+        let expected_last = unsafe { poseidon2_hash(&padded_last) };
+        assert_eq!(layer.len(), 2);
+        assert_eq!(layer[1].data, expected_last.data);
+    }
+
+    #[test]
+    fn test_poseidon2_merkle_root_matches_manual_folding() {
+        let leaves = vec![
+            Fr { data: [1u8; 32] },
+            Fr { data: [2u8; 32] },
+            Fr { data: [3u8; 32] },
+            Fr { data: [4u8; 32] },
+        ];
+
+        let root = unsafe { poseidon2_merkle_root(&leaves, 2) };
+
+        let layer1 = unsafe { poseidon2_tree_layer(&leaves, 2) };
+        let expected_root = unsafe { poseidon2_hash(&layer1) };
+        assert_eq!(root.data, expected_root.data);
+    }
+
+    #[test]
+    fn test_poseidon2_merkle_root_single_leaf() {
+        let leaves = vec![Fr { data: [7u8; 32] }];
+
+        let root = unsafe { poseidon2_merkle_root(&leaves, 2) };
+
+        assert_eq!(root.data, leaves[0].data);
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use crate::barretenberg_api::models::Fr;
+    use crate::barretenberg_api::poseidon_schnorr::{keygen, sign, sign_with_nonce, verify, NonceSource};
+
+    struct FixedNonce(Fr);
+
+    impl NonceSource for FixedNonce {
+        unsafe fn nonce(&mut self) -> Fr {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_sign_then_verify_round_trip() {
+        unsafe {
+            let (sk, pk) = keygen();
+            let msg = [Fr::from_u64(1), Fr::from_u64(2), Fr::from_u64(3)];
+
+            let signature = sign(&sk, &msg);
+
+            assert!(verify(&pk, &msg, &signature));
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_message() {
+        unsafe {
+            let (sk, pk) = keygen();
+            let msg = [Fr::from_u64(1), Fr::from_u64(2)];
+            let wrong_msg = [Fr::from_u64(1), Fr::from_u64(99)];
+
+            let signature = sign(&sk, &msg);
+
+            assert!(!verify(&pk, &wrong_msg, &signature));
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_key() {
+        unsafe {
+            let (sk, _pk) = keygen();
+            let (_other_sk, other_pk) = keygen();
+            let msg = [Fr::from_u64(7)];
+
+            let signature = sign(&sk, &msg);
+
+            assert!(!verify(&other_pk, &msg, &signature));
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_identity_public_key() {
+        unsafe {
+            let (sk, _pk) = keygen();
+            let msg = [Fr::from_u64(7)];
+            let signature = sign(&sk, &msg);
+
+            let identity = crate::barretenberg_api::models::Point {
+                x: Fr::from_u64(0),
+                y: Fr::from_u64(0),
+            };
+
+            assert!(!verify(&identity, &msg, &signature));
+        }
+    }
+
+    #[test]
+    fn test_different_nonces_produce_different_signatures() {
+        unsafe {
+            let (sk, pk) = keygen();
+            let msg = [Fr::from_u64(42)];
+
+            let sig1 = sign_with_nonce(&sk, &msg, &mut FixedNonce(Fr::from_u64(11)));
+            let sig2 = sign_with_nonce(&sk, &msg, &mut FixedNonce(Fr::from_u64(22)));
+
+            assert_ne!(sig1, sig2);
+            assert!(verify(&pk, &msg, &sig1));
+            assert!(verify(&pk, &msg, &sig2));
+        }
+    }
+
+    #[test]
+    fn test_empty_message_round_trip() {
+        unsafe {
+            let (sk, pk) = keygen();
+            let msg: [Fr; 0] = [];
+
+            let signature = sign(&sk, &msg);
+
+            assert!(verify(&pk, &msg, &signature));
+        }
+    }
+}
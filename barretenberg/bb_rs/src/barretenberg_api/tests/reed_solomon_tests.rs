@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use crate::barretenberg_api::reed_solomon::{
+        bytes_to_polynomial, domain_root_of_unity, polynomial_to_bytes, rs_decode, rs_encode,
+        ReedSolomonError,
+    };
+
+    #[test]
+    fn test_bytes_to_polynomial_roundtrip() {
+        let data = b"a Reed-Solomon data-availability blob, long enough to span several field elements".to_vec();
+        let coeffs = bytes_to_polynomial(&data);
+        let mut roundtripped = polynomial_to_bytes(&coeffs);
+        roundtripped.truncate(data.len());
+        assert_eq!(roundtripped, data);
+    }
+
+    #[test]
+    fn test_domain_root_of_unity_has_correct_order() {
+        let n = 8;
+        let root = domain_root_of_unity(n).unwrap();
+        assert_eq!(root.pow(n as u64), crate::barretenberg_api::models::Fr::one());
+        assert_ne!(root, crate::barretenberg_api::models::Fr::one());
+    }
+
+    #[test]
+    fn test_domain_root_of_unity_rejects_non_power_of_two() {
+        assert!(matches!(
+            domain_root_of_unity(6),
+            Err(ReedSolomonError::DomainNotPowerOfTwo { n: 6 })
+        ));
+    }
+
+    #[test]
+    fn test_rs_encode_decode_roundtrip() {
+        let data = b"erasure coded".to_vec();
+        let coeffs = bytes_to_polynomial(&data);
+        let k = coeffs.len();
+        let n = 8 * k;
+
+        let evaluations = rs_encode(&coeffs, n).unwrap();
+
+        // Drop every other evaluation; any k of the n should still suffice to decode.
+        let samples: Vec<(usize, _)> = evaluations
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| i % 2 == 0)
+            .map(|(i, v)| (i, *v))
+            .take(k)
+            .collect();
+
+        let decoded = rs_decode(&samples, k, n).unwrap();
+        assert_eq!(decoded, coeffs);
+    }
+
+    #[test]
+    fn test_rs_decode_rejects_too_few_samples() {
+        let data = b"short".to_vec();
+        let coeffs = bytes_to_polynomial(&data);
+        let k = coeffs.len();
+        let n = 8 * k.max(1);
+        let evaluations = rs_encode(&coeffs, n).unwrap();
+
+        let samples: Vec<(usize, _)> = evaluations.iter().copied().enumerate().take(k - 1).collect();
+        assert!(matches!(
+            rs_decode(&samples, k, n),
+            Err(ReedSolomonError::NotEnoughSamples { .. })
+        ));
+    }
+}
@@ -1,10 +1,11 @@
 #[cfg(test)]
 mod tests {
     use crate::barretenberg_api::schnorr::{
-        schnorr_compute_public_key, schnorr_construct_signature, 
-        schnorr_verify_signature, schnorr_multisig_create_multisig_public_key
+        aggregate_public_keys, combine_signatures, construct_signature_round_1,
+        construct_signature_round_2, schnorr_compute_public_key, schnorr_construct_signature,
+        schnorr_multisig_create_multisig_public_key, schnorr_verify_signature,
     };
-    use crate::barretenberg_api::models::{Fr, Point, Fq};
+    use crate::barretenberg_api::models::{Fr, Point};
 
     // Basic Schnorr tests
     #[test]
@@ -138,7 +139,7 @@ mod tests {
 
     #[test]
     fn test_schnorr_multisig_public_key_creation() {
-        let private_key = Fq { data: [9u8; 32] };
+        let private_key = Fr { data: [9u8; 32] };
         
         unsafe {
             let multisig_pubkey = schnorr_multisig_create_multisig_public_key(&private_key);
@@ -257,4 +258,89 @@ mod tests {
             assert_ne!(public_key.y.data, [0u8; 32]);
         }
     }
-} 
+
+    fn musig_round_1_for(signers: &[Fr]) -> (Vec<[u8; 128]>, Vec<([u8; 128], [u8; 64])>) {
+        let multisig_pubkeys: Vec<[u8; 128]> = signers
+            .iter()
+            .map(|key| unsafe { schnorr_multisig_create_multisig_public_key(key) })
+            .collect();
+        let round1: Vec<([u8; 128], [u8; 64])> = signers
+            .iter()
+            .map(|_| construct_signature_round_1())
+            .collect();
+        (multisig_pubkeys, round1)
+    }
+
+    #[test]
+    fn test_schnorr_musig_3_of_3_end_to_end() {
+        let message = b"3-of-3 MuSig session";
+        let signers = [
+            Fr { data: [1u8; 32] },
+            Fr { data: [2u8; 32] },
+            Fr { data: [3u8; 32] },
+        ];
+        // The multisig private key shares the Grumpkin scalar field with the signing key used
+        // in `construct_signature_round_2`, so this is the same value in both forms.
+        let signing_keys: Vec<Fr> = signers.iter().map(|key| Fr { data: key.data }).collect();
+
+        unsafe {
+            let (multisig_pubkeys, round1) = musig_round_1_for(&signers);
+            let round1_public: Vec<[u8; 128]> = round1.iter().map(|(public, _)| *public).collect();
+
+            let aggregate_pubkey =
+                aggregate_public_keys(&multisig_pubkeys).expect("aggregation should succeed");
+
+            let round2: Vec<[u8; 32]> = signing_keys
+                .iter()
+                .zip(round1.iter())
+                .map(|(signing_key, (_, private))| {
+                    construct_signature_round_2(message, signing_key, private, &round1_public)
+                })
+                .collect();
+
+            let (mut sig_s, mut sig_e) =
+                combine_signatures(message, &multisig_pubkeys, &round1_public, &round2)
+                    .expect("combining a well-formed session should succeed");
+
+            let is_valid =
+                schnorr_verify_signature(message, &aggregate_pubkey, &mut sig_s, &mut sig_e);
+            assert!(is_valid, "aggregate signature should verify");
+        }
+    }
+
+    #[test]
+    fn test_schnorr_musig_mismatched_round1_commitment_fails_to_combine() {
+        let message = b"3-of-3 MuSig session with a bad commitment";
+        let signers = [
+            Fr { data: [4u8; 32] },
+            Fr { data: [5u8; 32] },
+            Fr { data: [6u8; 32] },
+        ];
+        let signing_keys: Vec<Fr> = signers.iter().map(|key| Fr { data: key.data }).collect();
+
+        unsafe {
+            let (multisig_pubkeys, round1) = musig_round_1_for(&signers);
+            let mut round1_public: Vec<[u8; 128]> =
+                round1.iter().map(|(public, _)| *public).collect();
+
+            let round2: Vec<[u8; 32]> = signing_keys
+                .iter()
+                .zip(round1.iter())
+                .map(|(signing_key, (_, private))| {
+                    construct_signature_round_2(message, signing_key, private, &round1_public)
+                })
+                .collect();
+
+            // Swap in a commitment the other signers never saw when computing their round-2
+            // shares, so combination must fail.
+            let (other_public, _) = construct_signature_round_1();
+            round1_public[0] = other_public;
+
+            let combined = combine_signatures(message, &multisig_pubkeys, &round1_public, &round2);
+            assert!(
+                combined.is_none(),
+                "combining with a mismatched round-1 commitment should return None"
+            );
+        }
+    }
+}
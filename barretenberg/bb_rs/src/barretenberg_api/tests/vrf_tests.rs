@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use crate::barretenberg_api::models::Fr;
+    use crate::barretenberg_api::poseidon_schnorr::{keygen, NonceSource};
+    use crate::barretenberg_api::vrf::{prove, prove_with_nonce, verify};
+
+    struct FixedNonce(Fr);
+
+    impl NonceSource for FixedNonce {
+        unsafe fn nonce(&mut self) -> Fr {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_prove_then_verify_round_trip() {
+        unsafe {
+            let (sk, pk) = keygen();
+            let input = [Fr::from_u64(1), Fr::from_u64(2)];
+
+            let (output, proof) = prove(&sk, &input);
+
+            assert!(verify(&pk, &input, &output, &proof));
+        }
+    }
+
+    #[test]
+    fn test_output_is_deterministic_in_the_secret_and_input() {
+        unsafe {
+            let (sk, _pk) = keygen();
+            let input = [Fr::from_u64(5)];
+
+            let (output1, _) = prove_with_nonce(&sk, &input, &mut FixedNonce(Fr::from_u64(11)));
+            let (output2, _) = prove_with_nonce(&sk, &input, &mut FixedNonce(Fr::from_u64(22)));
+
+            // Different proof nonces still certify the same output, since output only depends on
+            // gamma = sk*H, not on the proof's nonce.
+            assert_eq!(output1.data, output2.data);
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_output() {
+        unsafe {
+            let (sk, pk) = keygen();
+            let input = [Fr::from_u64(1)];
+
+            let (_output, proof) = prove(&sk, &input);
+            let wrong_output = Fr::from_u64(999);
+
+            assert!(!verify(&pk, &input, &wrong_output, &proof));
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_input() {
+        unsafe {
+            let (sk, pk) = keygen();
+            let input = [Fr::from_u64(1)];
+            let wrong_input = [Fr::from_u64(2)];
+
+            let (output, proof) = prove(&sk, &input);
+
+            assert!(!verify(&pk, &wrong_input, &output, &proof));
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_key() {
+        unsafe {
+            let (sk, _pk) = keygen();
+            let (_other_sk, other_pk) = keygen();
+            let input = [Fr::from_u64(1)];
+
+            let (output, proof) = prove(&sk, &input);
+
+            assert!(!verify(&other_pk, &input, &output, &proof));
+        }
+    }
+
+    #[test]
+    fn test_different_inputs_produce_different_outputs() {
+        unsafe {
+            let (sk, _pk) = keygen();
+
+            let (output1, _) = prove(&sk, &[Fr::from_u64(1)]);
+            let (output2, _) = prove(&sk, &[Fr::from_u64(2)]);
+
+            assert_ne!(output1.data, output2.data);
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use crate::barretenberg_api::bindgen;
 use crate::barretenberg_api::utils::{DeserializeBuffer, SerializeBuffer};
 use crate::models::Fr;
+use num_bigint::BigUint;
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
 
 /// Compute the square root of a field element in BN254's Fr field
 /// Returns Some(sqrt) if the square root exists, None otherwise
@@ -23,3 +26,179 @@ pub unsafe fn bn254_fr_sqrt(input: &Fr) -> Option<Fr> {
         None
     }
 }
+
+/// Returns both square roots of `input` in BN254's Fr field, `(r, p - r)`, if `input` is a
+/// quadratic residue.
+pub unsafe fn bn254_fr_sqrt_both(input: &Fr) -> Option<(Fr, Fr)> {
+    let r = unsafe { bn254_fr_sqrt(input) }?;
+
+    let modulus = BigUint::from_bytes_be(&Fr::MODULUS);
+    let r_value = BigUint::from_bytes_be(&r.data);
+    let complement_bytes = (modulus - r_value).to_bytes_be();
+    let mut complement_data = [0u8; 32];
+    complement_data[32 - complement_bytes.len()..].copy_from_slice(&complement_bytes);
+    let complement = Fr::from_raw(complement_data);
+
+    Some((r, complement))
+}
+
+/// Returns the canonical square root of `input`: whichever of the two roots has its
+/// least-significant bit clear (the "even" root).
+///
+/// `r` and `p - r` always have opposite parity, since the BN254 scalar field modulus `p` is odd,
+/// so exactly one of them is even; this makes the choice stable across runs regardless of which
+/// root the backend happens to return, and `canonical^2 == input` holds either way.
+pub unsafe fn bn254_fr_sqrt_canonical(input: &Fr) -> Option<Fr> {
+    let (r, complement) = unsafe { bn254_fr_sqrt_both(input) }?;
+    let r_is_even = r.data[31] & 1 == 0;
+    Some(if r_is_even { r } else { complement })
+}
+
+//------------------------ Pure-Rust reference sqrt, for cross-checking the FFI -----------------
+
+/// The 2-adicity of `p - 1` for BN254's Fr field: `p - 1 = 2^FR_TWO_ADICITY * T` with `T` odd.
+const FR_TWO_ADICITY: u32 = 28;
+
+/// Width, in bits, of each chunk [`bn254_fr_sqrt_reference`]'s discrete-log search resolves at a
+/// time. The last chunk is narrower when `FR_TWO_ADICITY` isn't a multiple of this.
+const CHUNK_BITS: u32 = 8;
+
+/// A fixed quadratic non-residue of BN254's Fr field (`5^((p-1)/2) == -1`), used to derive the
+/// generator of the order-`2^FR_TWO_ADICITY` subgroup below.
+fn non_residue() -> Fr {
+    Fr::from_u64(5)
+}
+
+/// Exponentiates `base` by a `BigUint` exponent, the same reduce-mod-`p` path [`Fr::pow`] takes
+/// for a `u64` exponent, but for the far larger exponents (e.g. the odd cofactor `T`, ~2^226) this
+/// module needs.
+fn modpow_biguint(base: &Fr, exponent: &BigUint) -> Fr {
+    let modulus = BigUint::from_bytes_be(&Fr::MODULUS);
+    let base_value = BigUint::from_bytes_be(&base.data);
+    let result = base_value.modpow(exponent, &modulus);
+    let bytes = result.to_bytes_be();
+    let mut data = [0u8; 32];
+    data[32 - bytes.len()..].copy_from_slice(&bytes);
+    Fr::from_raw(data)
+}
+
+/// `T`, the odd cofactor of `p - 1 = 2^FR_TWO_ADICITY * T`.
+fn odd_cofactor() -> BigUint {
+    let modulus = BigUint::from_bytes_be(&Fr::MODULUS);
+    (modulus - BigUint::from(1u8)) >> FR_TWO_ADICITY
+}
+
+/// The generator `g = z^T` of the unique order-`2^FR_TWO_ADICITY` subgroup of `Fr`'s
+/// multiplicative group, where `z` is [`non_residue`].
+fn two_adic_generator() -> Fr {
+    static GENERATOR: OnceLock<Fr> = OnceLock::new();
+    *GENERATOR.get_or_init(|| modpow_biguint(&non_residue(), &odd_cofactor()))
+}
+
+/// The width of each chunk [`bn254_fr_sqrt_reference`] resolves, from the least-significant end of
+/// the `FR_TWO_ADICITY`-bit discrete log up: `CHUNK_BITS`-wide, except a possibly narrower final
+/// chunk.
+fn chunk_widths() -> Vec<u32> {
+    let mut widths = Vec::new();
+    let mut shift = 0u32;
+    while shift < FR_TWO_ADICITY {
+        let width = CHUNK_BITS.min(FR_TWO_ADICITY - shift);
+        widths.push(width);
+        shift += width;
+    }
+    widths
+}
+
+/// For each distinct chunk width `w` that occurs, the table mapping `h^k -> k` for
+/// `h = g^(2^(FR_TWO_ADICITY - w))` (the generator of the order-`2^w` subgroup of `g`) and
+/// `k` in `0..2^w`. Shared across every chunk of the same width, since `h` only depends on `w`.
+fn chunk_tables() -> &'static HashMap<u32, HashMap<[u8; 32], u32>> {
+    static TABLES: OnceLock<HashMap<u32, HashMap<[u8; 32], u32>>> = OnceLock::new();
+    TABLES.get_or_init(|| {
+        let g = two_adic_generator();
+        chunk_widths()
+            .into_iter()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .map(|width| {
+                let h = g.pow(1u64 << (FR_TWO_ADICITY - width));
+                let mut table = HashMap::new();
+                let mut power = Fr::one();
+                for k in 0..(1u32 << width) {
+                    table.insert(power.data, k);
+                    power = power.mul(&h);
+                }
+                (width, table)
+            })
+            .collect()
+    })
+}
+
+/// A pure-Rust, Tonelli-Shanks-based square root for BN254's scalar field, independent of the
+/// Barretenberg FFI. Used to cross-check [`bn254_fr_sqrt`] against random (not just tiny
+/// perfect-square) inputs.
+///
+/// Write `p - 1 = 2^FR_TWO_ADICITY * T` (`T` odd) and `g` for the generator of the order-
+/// `2^FR_TWO_ADICITY` subgroup ([`two_adic_generator`]). For a quadratic residue `a`: `x = a^((T+1)/2)`
+/// and `t = a^T` satisfy `x^2 = a * t`, and `t` lies in `<g>`, say `t = g^e`. Since `a` is a
+/// residue, `e` is even; writing `e = 2k`, `y = x * g^(-k)` satisfies `y^2 = x^2 * g^(-e) = a * t *
+/// g^(-e) = a`.
+///
+/// The remaining work is finding `e` — the discrete log of `t` with respect to `g`, within the
+/// order-`2^FR_TWO_ADICITY` cyclic group. Rather than resolving it one bit at a time (the textbook
+/// Tonelli-Shanks loop), this resolves it [`CHUNK_BITS`] at a time via the precomputed
+/// [`chunk_tables`], the least-significant chunk first: at shift `s` with remaining chunk width
+/// `w`, `t`'s current value (after cancelling the bits already found) raised to
+/// `2^(FR_TWO_ADICITY - s - w)` lands in the order-`2^w` subgroup generated by
+/// `g^(2^(FR_TWO_ADICITY - w))`, and a table lookup reads off the next `w` bits directly. This
+/// trades the usual `FR_TWO_ADICITY` squarings-and-branches for `ceil(FR_TWO_ADICITY /
+/// CHUNK_BITS)` table lookups, the same trick used to speed up the analogous square root in other
+/// two-adic-subgroup-based field implementations (e.g. the pasta curves).
+pub fn bn254_fr_sqrt_reference(input: &Fr) -> Option<Fr> {
+    if input.data == [0u8; 32] {
+        return Some(Fr::zero());
+    }
+
+    let modulus = BigUint::from_bytes_be(&Fr::MODULUS);
+    let legendre_exponent = (&modulus - BigUint::from(1u8)) >> 1u32;
+    if modpow_biguint(input, &legendre_exponent) != Fr::one() {
+        return None;
+    }
+
+    let t_exp = odd_cofactor();
+    let mut x = modpow_biguint(input, &((&t_exp + BigUint::from(1u8)) >> 1u32));
+    let mut t = modpow_biguint(input, &t_exp);
+
+    if t == Fr::one() {
+        return Some(x);
+    }
+
+    let g = two_adic_generator();
+    let tables = chunk_tables();
+
+    let mut e = BigUint::from(0u8);
+    let mut shift = 0u32;
+    let mut g_pow_shift = g; // g^(2^shift)
+    for width in chunk_widths() {
+        let reduce_exp = FR_TWO_ADICITY - shift - width;
+        let u = t.pow(1u64 << reduce_exp);
+        let table = tables.get(&width).expect("table for this width was precomputed");
+        let chunk_value = *table
+            .get(&u.data)
+            .expect("t lies in <g>, so its reduction must appear in the precomputed table");
+
+        e += BigUint::from(chunk_value) << shift;
+        if chunk_value != 0 {
+            t = t.mul(&g_pow_shift.pow(chunk_value as u64).inverse());
+        }
+        for _ in 0..width {
+            g_pow_shift = g_pow_shift.mul(&g_pow_shift);
+        }
+        shift += width;
+    }
+
+    // `input` is a residue, so `e` (the discrete log of the original `t` w.r.t. `g`) is even.
+    let half_e = e >> 1u32;
+    x = x.mul(&modpow_biguint(&g, &half_e).inverse());
+    Some(x)
+}
@@ -0,0 +1,2 @@
+pub(crate) mod bn254;
+pub(crate) mod secp256k1;
@@ -2,6 +2,14 @@ use crate::barretenberg_api::bindgen;
 use crate::barretenberg_api::utils::{DeserializeBuffer, SerializeBuffer};
 use crate::models::{Fr, Point};
 
+// A secp256k1 twin of `grumpkin`'s `ecc_grumpkin__msm`/`WnafTable` (Pippenger MSM and windowed-NAF
+// repeated multiplication) isn't implementable against this FFI surface: both techniques are built
+// out of point *additions* between mul calls, and unlike `ecc_grumpkin__add`, this binding exposes
+// no `ecc_secp256k1__add` at all -- only `mul`, the random-scalar draw, and `reduce512`. Without an
+// addition primitive there's no way to combine Pippenger's per-window bucket sums, or to add a
+// wNAF table entry into a running accumulator, over this curve from Rust. Adding either would need
+// a new C++ entry point first.
+
 /// Scalar multiplication on Secp256k1 curve: point * scalar
 pub unsafe fn ecc_secp256k1__mul(point: &Point, scalar: &Fr) -> Point {
     let mut result_buf = [0; 64];
@@ -0,0 +1,74 @@
+use std::slice;
+
+/// Serializes a value into the length-prefixed big-endian wire format the C++ bindings expect.
+pub trait SerializeBuffer {
+    fn to_buffer(&self) -> Vec<u8>;
+}
+
+impl<T: SerializeBuffer> SerializeBuffer for &[T] {
+    fn to_buffer(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(&(self.len() as u32).to_be_bytes());
+        for elem in self.iter() {
+            buffer.extend_from_slice(&elem.to_buffer());
+        }
+        buffer
+    }
+}
+
+impl<T: SerializeBuffer> SerializeBuffer for Vec<T> {
+    fn to_buffer(&self) -> Vec<u8> {
+        self.as_slice().to_buffer()
+    }
+}
+
+impl SerializeBuffer for u8 {
+    fn to_buffer(&self) -> Vec<u8> {
+        vec![*self]
+    }
+}
+
+/// Deserializes a value out of a fixed-size byte array written by the C++ bindings.
+pub trait DeserializeBuffer {
+    /// The fixed-size byte array produced by the FFI call for this type.
+    type Slice: Default + AsRef<[u8]> + AsMut<[u8]>;
+
+    fn from_buffer(slice: Self::Slice) -> Self;
+}
+
+/// A heap buffer allocated by the C++ side and owned on the Rust side once copied out.
+///
+/// The wire format is a 4-byte big-endian length prefix followed by that many bytes of data.
+pub struct Buffer {
+    data: Vec<u8>,
+}
+
+impl Buffer {
+    pub fn from_data(data: Vec<u8>) -> Self {
+        Self { data }
+    }
+
+    /// Reads a length-prefixed buffer out of a raw pointer returned by the C++ side.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be null or point to a valid `[u32 length][data...]` allocation produced by the
+    /// C++ `to_heap_buffer` helper.
+    pub unsafe fn from_ptr(ptr: *const u8) -> Option<Self> {
+        if ptr.is_null() {
+            return None;
+        }
+        let len_bytes = slice::from_raw_parts(ptr, 4);
+        let len = u32::from_be_bytes([len_bytes[0], len_bytes[1], len_bytes[2], len_bytes[3]]) as usize;
+        let data = slice::from_raw_parts(ptr.add(4), len).to_vec();
+        Some(Self { data })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        self.data
+    }
+}
@@ -0,0 +1,117 @@
+//! Elliptic-curve verifiable random function (ECVRF) over Grumpkin with Poseidon2, layered on the
+//! same primitives as [`crate::barretenberg_api::poseidon_schnorr`].
+//!
+//! `prove` derives a base point `H` from the VRF input via hash-to-curve, computes
+//! `gamma = sk·H` and the VRF `output = poseidon2_hash(&[gamma.x, gamma.y])`, and attaches a proof
+//! that lets `verify` check `output` was derived from the secret scalar behind a claimed public
+//! key, without revealing the scalar or needing the prover to be online.
+
+use crate::barretenberg_api::grumpkin::{ecc_grumpkin__add, ecc_grumpkin__mul};
+use crate::barretenberg_api::models::{Fr, Point};
+use crate::barretenberg_api::poseidon2::poseidon2_hash;
+use crate::barretenberg_api::poseidon_schnorr::{
+    generator, scalar_add, scalar_mul, NonceSource, RandomNonce,
+};
+use crate::barretenberg_api::untested::bn254::bn254_fr_sqrt;
+
+/// A proof accompanying a VRF output: the intermediate point `gamma = sk·H`, and the Schnorr-style
+/// challenge/response pair `(c, s)` proving `gamma` was computed with the secret scalar behind the
+/// public key `verify` is called against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VrfProof {
+    pub gamma: Point,
+    pub c: Fr,
+    pub s: Fr,
+}
+
+/// Negates a point by flipping its `y` coordinate, the standard inverse on a short Weierstrass
+/// curve — the only point-subtraction primitive this module needs, since the FFI only exposes
+/// `ecc_grumpkin__add`.
+fn negate(point: &Point) -> Point {
+    Point {
+        x: point.x,
+        y: point.y.neg(),
+    }
+}
+
+/// Grumpkin's curve equation is `y^2 = x^3 - 17` (`a = 0`, `b = -17`); [`hash_to_curve`] uses this
+/// to test whether a candidate `x` lands on the curve.
+fn curve_rhs(x: &Fr) -> Fr {
+    let x_squared = x.mul(x);
+    let x_cubed = x_squared.mul(x);
+    x_cubed.sub(&Fr::from_u64(17))
+}
+
+/// Hashes `input` to a point on the curve via candidate-and-increment: hash `input` with an
+/// incrementing counter appended to get a candidate `x`, and accept the first one for which
+/// `x^3 - 17` is a quadratic residue.
+fn hash_to_curve(input: &[Fr]) -> Point {
+    let mut counter = 0u64;
+    loop {
+        let mut hash_input = input.to_vec();
+        hash_input.push(Fr::from_u64(counter));
+        let x = unsafe { poseidon2_hash(&hash_input) };
+        if let Some(y) = unsafe { bn254_fr_sqrt(&curve_rhs(&x)) } {
+            return Point { x, y };
+        }
+        counter += 1;
+    }
+}
+
+/// Computes the VRF output and proof for `input` under `sk`, drawing the proof's nonce from the
+/// FFI's RNG.
+///
+/// # Panics/security note
+///
+/// The nonce `k` must be unique per proof — reusing it across two proofs under the same key, the
+/// way reusing a Schnorr nonce does, leaks `sk`. Use [`prove_with_nonce`] to supply a deterministic
+/// source instead.
+pub unsafe fn prove(sk: &Fr, input: &[Fr]) -> (Fr, VrfProof) {
+    unsafe { prove_with_nonce(sk, input, &mut RandomNonce) }
+}
+
+/// As [`prove`], but drawing the proof's nonce `k` from `nonce_source` instead of the FFI's RNG.
+pub unsafe fn prove_with_nonce(
+    sk: &Fr,
+    input: &[Fr],
+    nonce_source: &mut impl NonceSource,
+) -> (Fr, VrfProof) {
+    let h = hash_to_curve(input);
+    let gamma = unsafe { ecc_grumpkin__mul(&h, sk) };
+    let output = unsafe { poseidon2_hash(&[gamma.x, gamma.y]) };
+
+    let pk = unsafe { ecc_grumpkin__mul(&generator(), sk) };
+    let k = unsafe { nonce_source.nonce() };
+    let u = unsafe { ecc_grumpkin__mul(&generator(), &k) };
+    let v = unsafe { ecc_grumpkin__mul(&h, &k) };
+    let c = unsafe { poseidon2_hash(&[pk.x, h.x, gamma.x, u.x, v.x]) };
+    // `c`, `k`, `sk`, and `s` are Grumpkin scalars, not coordinate-field elements, so they must be
+    // combined modulo Grumpkin's true group order (see `poseidon_schnorr`'s module doc) rather
+    // than `Fr::add`/`Fr::mul`'s BN254 scalar-field modulus.
+    let s = scalar_add(&k, &scalar_mul(&c, sk));
+
+    (output, VrfProof { gamma, c, s })
+}
+
+/// Verifies that `output` is the VRF output for `input` under `pk`, per `proof`.
+pub unsafe fn verify(pk: &Point, input: &[Fr], output: &Fr, proof: &VrfProof) -> bool {
+    let h = hash_to_curve(input);
+
+    let expected_output = unsafe { poseidon2_hash(&[proof.gamma.x, proof.gamma.y]) };
+    if expected_output != *output {
+        return false;
+    }
+
+    let s_g = unsafe { ecc_grumpkin__mul(&generator(), &proof.s) };
+    let c_pk = unsafe { ecc_grumpkin__mul(pk, &proof.c) };
+    let u_prime = unsafe { ecc_grumpkin__add(&s_g, &negate(&c_pk)) };
+
+    let s_h = unsafe { ecc_grumpkin__mul(&h, &proof.s) };
+    let c_gamma = unsafe { ecc_grumpkin__mul(&proof.gamma, &proof.c) };
+    let v_prime = unsafe { ecc_grumpkin__add(&s_h, &negate(&c_gamma)) };
+
+    let c_prime =
+        unsafe { poseidon2_hash(&[pk.x, h.x, proof.gamma.x, u_prime.x, v_prime.x]) };
+
+    c_prime == proof.c
+}
@@ -1,7 +1,9 @@
 mod barretenberg_api;
 pub mod noir_api;
 
-pub use barretenberg_api::bbapi::{CircuitComputeVk, CircuitProve, CircuitVerify};
+pub use barretenberg_api::bbapi::{
+    aggregate_ultra_honk, BbContext, CircuitComputeVk, CircuitProve, CircuitVerify,
+};
 
 pub mod circuits {
     pub use crate::barretenberg_api::acir::{
@@ -18,8 +20,9 @@ pub mod ultra_honk {
 
 pub mod ultra_honk_keccak {
     pub use crate::barretenberg_api::bbapi::{
-        get_ultra_honk_keccak_verification_key as get_vk, prove_ultra_keccak_honk as prove,
-        verify_ultra_keccak_honk as verify,
+        get_ultra_honk_keccak_verification_key as get_vk,
+        get_ultra_keccak_honk_solidity_verifier as get_solidity_verifier,
+        prove_ultra_keccak_honk as prove, verify_ultra_keccak_honk as verify,
     };
 }
 
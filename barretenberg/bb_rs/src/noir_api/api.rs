@@ -1,20 +1,48 @@
-use fm::FileManager;
+use crate::barretenberg_api::acir::get_circuit_sizes;
+use crate::barretenberg_api::bbapi::get_ultra_keccak_honk_solidity_verifier;
+use crate::noir_api::inputs::Inputs;
+use crate::noir_api::oracle::{OracleResolver, PluggableForeignCallExecutor};
+use acir::native_types::{WitnessMap, WitnessStack};
+use acir::{bincode_serialize, FieldElement};
+use bn254_blackbox_solver::Bn254BlackBoxSolver;
+use fm::{FileId, FileManager};
 use log::*;
+use nargo::foreign_calls::{DefaultForeignCallBuilder, ForeignCallExecutor};
+use nargo::ops::compile_contract as compile_contract_package;
 use nargo::ops::compile_program;
-use nargo::ops::debug::load_workspace_files;
-use nargo::{
-    insert_all_files_for_workspace_into_file_manager, insert_all_files_under_path, parse_all,
-    prepare_package,
-};
+use nargo::ops::execute_program;
+use nargo::{insert_all_files_for_workspace_into_file_manager, insert_all_files_under_path, prepare_package};
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use noirc_artifacts::contract::CompiledContract;
 use noirc_artifacts::program::ProgramArtifact;
-use noirc_driver::{check_crate, compile_main, CompileOptions, NOIR_ARTIFACT_VERSION_STRING};
+use noirc_driver::{
+    check_crate, compile_main, CompileOptions, ParsedFiles, NOIR_ARTIFACT_VERSION_STRING,
+};
+use noirc_errors::debug_info::{DebugFile, DebugInfo};
+use noirc_frontend::parse_program;
+use rayon::prelude::*;
+use std::collections::BTreeMap;
 use std::path::Path;
 use thiserror::Error;
 
 pub struct CompilationResult {
     pub program: ProgramArtifact,
     pub warnings: Vec<String>,
+    /// Each circuit's opcode -> source location mapping, captured at compile time. If a later
+    /// transformation (e.g. backend-specific optimization) shifts opcode indices, pass the
+    /// transformation's own old -> new opcode map to
+    /// [`remap_after_transform`](crate::noir_api::debug_info::remap_after_transform) alongside
+    /// the matching entry here to keep source attribution correct.
+    pub debug_info: Vec<DebugInfo>,
+}
+
+pub struct ContractCompilationResult {
+    pub contract: CompiledContract,
+    pub warnings: Vec<String>,
+    /// Maps every file the contract's functions were compiled from back to its debug info, so
+    /// downstream tooling can resolve an opcode's source location without re-parsing the
+    /// workspace.
+    pub file_map: BTreeMap<FileId, DebugFile>,
 }
 
 #[derive(Debug, Error)]
@@ -28,6 +56,8 @@ pub enum NoirError {
     Execution(String),
     #[error("Your Nargo workspace is not correctly configured: {0}")]
     Workspace(String),
+    #[error("unsupported feature: {0}")]
+    UnsupportedFeature(String),
 }
 
 /// Compiles a Noir program located at the given nargo workspace path.
@@ -45,6 +75,162 @@ pub enum NoirError {
 pub fn compile(
     nargo_path: impl AsRef<Path>,
     settings: CompileOptions,
+) -> Result<CompilationResult, NoirError> {
+    compile_inner(nargo_path, settings, true)
+}
+
+/// Like [`compile`], but parses the workspace's files one at a time instead of across a rayon
+/// thread pool.
+///
+/// Use this on single-core or WASM-ish targets where there's no thread pool to gain from and the
+/// parallel path's setup isn't worth paying for.
+pub fn compile_serial(
+    nargo_path: impl AsRef<Path>,
+    settings: CompileOptions,
+) -> Result<CompilationResult, NoirError> {
+    compile_inner(nargo_path, settings, false)
+}
+
+/// Compiles one or more binary packages in the nargo workspace rooted at `nargo_path`, selected by
+/// `selection` instead of the single implicit "default" binary [`compile`] always targets.
+///
+/// Use [`PackageSelection::Selected`] to build one named package out of a workspace with several
+/// binaries, or [`PackageSelection::All`]/[`PackageSelection::DefaultOrAll`] to build every binary
+/// package in the workspace at once. Either way, the result has one [`CompilationResult`] per
+/// compiled package, in workspace member order.
+///
+/// # Arguments
+/// - `nargo_path`: same as [`compile`].
+/// - `settings`: same as [`compile`].
+/// - `selection`: which binary package(s) to compile.
+///
+/// # Errors
+/// Returns [`NoirError::Workspace`] naming every binary package available in the workspace if
+/// `selection` names a package that doesn't exist.
+pub fn compile_workspace(
+    nargo_path: impl AsRef<Path>,
+    settings: CompileOptions,
+    selection: PackageSelection,
+) -> Result<Vec<CompilationResult>, NoirError> {
+    let path = nargo_path.as_ref();
+    let toml_path = get_package_manifest(path).map_err(|e| NoirError::Workspace(e.to_string()))?;
+
+    // Resolve with `All` regardless of `selection` so a by-name miss can be reported with every
+    // package that *is* available, rather than whatever terse error `nargo_toml` raises itself.
+    let workspace = resolve_workspace_from_toml(
+        &toml_path,
+        PackageSelection::All,
+        Some(NOIR_ARTIFACT_VERSION_STRING.to_owned()),
+    )
+    .map_err(|e| NoirError::Workspace(e.to_string()))?;
+    debug!(
+        "Workspace recreated from manifest. {} members found.",
+        workspace.members.len()
+    );
+
+    let binary_names: Vec<String> = workspace
+        .members
+        .iter()
+        .filter(|p| p.is_binary())
+        .map(|p| p.name.to_string())
+        .collect();
+
+    let wanted_name = match &selection {
+        PackageSelection::Selected(name) => Some(name.to_string()),
+        PackageSelection::All | PackageSelection::DefaultOrAll => None,
+    };
+
+    if let Some(name) = &wanted_name {
+        if !binary_names.contains(name) {
+            return Err(NoirError::Workspace(format!(
+                "no binary package named '{name}' in this workspace; available binary packages: {}",
+                binary_names.join(", ")
+            )));
+        }
+    }
+
+    let mut file_manager = FileManager::new(path);
+    insert_all_files_for_workspace_into_file_manager(&workspace, &mut file_manager);
+    let parsed_files = parse_workspace(&file_manager, true);
+    debug!("File manager created successfully.");
+    debug!("{} files parsed.", parsed_files.len());
+
+    let mut results = Vec::new();
+    for package in workspace.into_iter().filter(|p| {
+        p.is_binary()
+            && match &wanted_name {
+                Some(name) => &p.name.to_string() == name,
+                None => true,
+            }
+    }) {
+        debug!(
+            "Package {} created from workspace. Entry path: {}",
+            package.name,
+            package.entry_path.display()
+        );
+
+        let (program, warnings) = compile_program(
+            &file_manager,
+            &parsed_files,
+            &workspace,
+            package,
+            &settings,
+            None,
+        )
+        .map_err(|all| {
+            let (warnings, errors): (Vec<_>, Vec<_>) =
+                all.into_iter().partition(|e| e.is_warning());
+            let warnings = warnings.into_iter().map(|w| w.to_string()).collect();
+            let errors = errors.into_iter().map(|e| e.to_string()).collect();
+            NoirError::Compilation { warnings, errors }
+        })?;
+        debug!("Compilation finished with {} warnings.", warnings.len());
+
+        let warnings = warnings.into_iter().map(|w| w.to_string()).collect();
+        let debug_info = program.debug.clone();
+        let program = ProgramArtifact::from(program);
+        results.push(CompilationResult {
+            program,
+            warnings,
+            debug_info,
+        });
+    }
+
+    if results.is_empty() {
+        return Err(NoirError::Workspace("No binary package found".to_string()));
+    }
+
+    Ok(results)
+}
+
+/// Parses every file in `file_manager`, producing the same [`ParsedFiles`] structure
+/// `compile_program` consumes.
+///
+/// Parsing dominates cold-start time on multi-file workspaces, so when `parallel` is true this
+/// runs across a rayon thread pool instead of file-by-file.
+fn parse_workspace(file_manager: &FileManager, parallel: bool) -> ParsedFiles {
+    if parallel {
+        file_manager
+            .as_file_map()
+            .all_file_ids()
+            .copied()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|file_id| (file_id, parse_program(file_manager.fetch_file(file_id))))
+            .collect()
+    } else {
+        file_manager
+            .as_file_map()
+            .all_file_ids()
+            .map(|&file_id| (file_id, parse_program(file_manager.fetch_file(file_id))))
+            .collect()
+    }
+}
+
+fn compile_inner(
+    nargo_path: impl AsRef<Path>,
+    settings: CompileOptions,
+    parallel: bool,
 ) -> Result<CompilationResult, NoirError> {
     let path = nargo_path.as_ref();
     // Load workspace
@@ -61,7 +247,9 @@ pub fn compile(
         workspace.members.len()
     );
 
-    let (file_manager, parsed_files) = load_workspace_files(&workspace);
+    let mut file_manager = FileManager::new(path);
+    insert_all_files_for_workspace_into_file_manager(&workspace, &mut file_manager);
+    let parsed_files = parse_workspace(&file_manager, parallel);
     debug!("File manager created successfully.");
     debug!("{} files parsed.", parsed_files.len());
 
@@ -93,36 +281,224 @@ pub fn compile(
     debug!("Compilation finished with {} warnings.", warnings.len());
 
     let warnings = warnings.into_iter().map(|w| w.to_string()).collect();
+    let debug_info = program.debug.clone();
     let program = ProgramArtifact::from(program);
-    Ok(CompilationResult { program, warnings })
+    Ok(CompilationResult {
+        program,
+        warnings,
+        debug_info,
+    })
 }
 
-// pub fn execute(
-//     program: &ProgramArtifact,
-//     inputs_map: WitnessMap<FieldElement>,
-//     pedantic_solving: bool,
-// ) -> Result<WitnessStack<FieldElement>, NoirError> {
-//     // Execute
-//     let mut foreign_call_executor = DefaultForeignCallBuilder::default()
-//         .with_output(Vec::<u8>::new())
-//         .with_mocks(false)
-//         .build();
-//
-//     let witness_stack = execute_program(
-//         &program,
-//         inputs_map,
-//         &Bn254BlackBoxSolver(pedantic_solving),
-//         &mut foreign_call_executor,
-//     ).map_err(|e| NoirError::Execution(e.to_string()))?;
-//
-//
-//
-//     Ok(witness_stack)
-// })
+/// Compiles a Noir contract (a package with multiple entrypoint functions, e.g. an Aztec-style
+/// contract) located at the given nargo workspace path.
+///
+/// Unlike [`compile`], this targets a contract package rather than a binary one, and the
+/// resulting [`CompiledContract`] carries a circuit plus [`DebugInfo`](noirc_errors::debug_info::DebugInfo)
+/// per function so each can be proved and debugged independently.
+///
+/// # Arguments
+/// - `nargo_path`: The file system path to the root of the nargo workspace containing the Noir
+/// contract project. This folder _must_ contain a `Nargo.toml` manifest file.
+/// - `settings`: Compilation settings to customize the compilation process.
+pub fn compile_contract(
+    nargo_path: impl AsRef<Path>,
+    settings: CompileOptions,
+) -> Result<ContractCompilationResult, NoirError> {
+    let path = nargo_path.as_ref();
+    let toml_path = get_package_manifest(path).map_err(|e| NoirError::Workspace(e.to_string()))?;
+
+    let workspace = resolve_workspace_from_toml(
+        &toml_path,
+        PackageSelection::DefaultOrAll,
+        Some(NOIR_ARTIFACT_VERSION_STRING.to_owned()),
+    )
+    .map_err(|e| NoirError::Workspace(e.to_string()))?;
+    debug!(
+        "Workspace recreated from manifest. {} members found.",
+        workspace.members.len()
+    );
+
+    let mut file_manager = FileManager::new(path);
+    insert_all_files_for_workspace_into_file_manager(&workspace, &mut file_manager);
+    let parsed_files = parse_workspace(&file_manager, true);
+    debug!("File manager created successfully.");
+    debug!("{} files parsed.", parsed_files.len());
+
+    let package = workspace
+        .into_iter()
+        .find(|p| p.is_contract())
+        .ok_or_else(|| NoirError::Workspace("No contract package found".to_string()))?;
+
+    debug!(
+        "Package {} created from workspace. Entry path: {}",
+        package.name,
+        package.entry_path.display()
+    );
+
+    let (contract, warnings) =
+        compile_contract_package(&file_manager, &parsed_files, package, &settings).map_err(
+            |all| {
+                let (warnings, errors): (Vec<_>, Vec<_>) =
+                    all.into_iter().partition(|e| e.is_warning());
+                let warnings = warnings.into_iter().map(|w| w.to_string()).collect();
+                let errors = errors.into_iter().map(|e| e.to_string()).collect();
+                NoirError::Compilation { warnings, errors }
+            },
+        )?;
+    debug!("Contract compilation finished with {} warnings.", warnings.len());
+
+    let warnings = warnings.into_iter().map(|w| w.to_string()).collect();
+    let file_map = contract.file_map.clone();
+    Ok(ContractCompilationResult {
+        contract,
+        warnings,
+        file_map,
+    })
+}
+
+/// Generates the Solidity source for an on-chain verifier contract matching `artifact`'s compiled
+/// circuit, so a team proving with [`crate::noir_api::Backend::UltraHonkKeccak`] can deploy a
+/// verifier without leaving this crate for the `bb` CLI.
+///
+/// This is a thin wrapper around the same
+/// [`get_ultra_keccak_honk_solidity_verifier`](crate::barretenberg_api::bbapi::get_ultra_keccak_honk_solidity_verifier)
+/// command `bb`'s own `write_solidity_verifier` uses: the contract it emits already recomputes
+/// and embeds the circuit's verification key from `artifact`'s bytecode, so there's no
+/// pairing-check or public-input-decoding template-rendering for this function to do itself.
+///
+/// # Arguments
+/// - `artifact`: The compiled program to generate a verifier for.
+/// - `vk`: The circuit's verification key, previously computed (e.g. via
+///   [`get_ultra_honk_keccak_verification_key`](crate::barretenberg_api::bbapi::get_ultra_honk_keccak_verification_key))
+///   for the same circuit as `artifact`. This is only checked for presence, not cross-checked
+///   against the key the generated contract embeds -- the underlying `bb` command recomputes its
+///   own verification key from `artifact` rather than accepting a precomputed one, and this crate
+///   has no standalone vk-equality primitive to compare the two. Keep `vk` in sync with
+///   `artifact` yourself, or the contract this returns and your client-side verifier can diverge.
+///
+/// # Errors
+/// Returns [`NoirError::UnsupportedFeature`] if `vk` is empty, if `artifact`'s circuit has no
+/// gates (nothing to verify), or if any of its functions is recursively verified: the contract
+/// this emits checks a standalone UltraKeccakHonk proof, not the IPA-accumulated aggregation a
+/// recursively-verified circuit needs, so emitting one anyway would silently verify the wrong
+/// thing rather than fail loudly.
+pub fn generate_solidity_verifier(
+    artifact: &ProgramArtifact,
+    vk: &[u8],
+) -> Result<String, NoirError> {
+    if vk.is_empty() {
+        return Err(NoirError::UnsupportedFeature(
+            "no verification key was provided".to_string(),
+        ));
+    }
+
+    if artifact
+        .bytecode
+        .functions
+        .iter()
+        .any(|function| function.recursive)
+    {
+        return Err(NoirError::UnsupportedFeature(
+            "recursively-verified circuits need IPA accumulation, which this Solidity verifier does not support".to_string(),
+        ));
+    }
+
+    let bytecode = bincode_serialize(&artifact.bytecode)
+        .map_err(|e| NoirError::Execution(format!("Failed to serialize bytecode: {e}")))?;
+
+    if get_circuit_sizes(&bytecode, false).total == 0 {
+        return Err(NoirError::UnsupportedFeature(
+            "circuit has no gates; there is nothing to verify".to_string(),
+        ));
+    }
+
+    let response = get_ultra_keccak_honk_solidity_verifier(&bytecode, false)
+        .map_err(|e| NoirError::Execution(e.to_string()))?;
+    Ok(response.contract)
+}
+
+/// Executes a compiled circuit against an already ABI-encoded witness map, producing the witness
+/// stack a prover needs.
+///
+/// The foreign call executor is generic rather than hardcoded, so embedders in mobile or
+/// constrained environments can supply their own [`ForeignCallExecutor`] to service `print`,
+/// custom oracles, or mocked calls however fits their host. Most callers with named [`Inputs`]
+/// want [`execute_with_inputs`] instead, which also handles ABI encoding and wires up a sensible
+/// default executor.
+///
+/// # Arguments
+/// - `program`: The compiled Noir program to execute.
+/// - `inputs_map`: The ABI-encoded witness map to provide to the program during execution.
+/// - `foreign_call_executor`: Resolves foreign calls (oracles and unconstrained functions) the
+/// program makes during execution.
+/// - `pedantic_solving`: If true, the solver performs additional checks during execution.
+pub fn execute<E: ForeignCallExecutor<FieldElement>>(
+    program: &ProgramArtifact,
+    inputs_map: WitnessMap<FieldElement>,
+    foreign_call_executor: &mut E,
+    pedantic_solving: bool,
+) -> Result<WitnessStack<FieldElement>, NoirError> {
+    execute_program(
+        &program.bytecode,
+        inputs_map,
+        &Bn254BlackBoxSolver(pedantic_solving),
+        foreign_call_executor,
+    )
+    .map_err(|e| NoirError::Execution(e.to_string()))
+}
+
+/// Executes a compiled circuit from named [`Inputs`], producing the witness stack a prover needs.
+///
+/// Any foreign call (an oracle or unconstrained function) with a handler registered in `oracles`
+/// is routed to that handler ahead of Noir's own mocks/print/RNG handling, so embedders without
+/// the Nargo CLI can back `print` or app-specific oracles instead of the execution failing
+/// outright. Calls with no registered handler go to the default handling.
+///
+/// # Arguments
+/// - `program`: The compiled Noir program to execute.
+/// - `inputs`: The input values to provide to the program during execution.
+/// - `oracles`: Host-provided handlers for named foreign calls.
+/// - `pedantic_solving`: If true, the solver performs additional checks during execution.
+pub fn execute_with_inputs(
+    program: &ProgramArtifact,
+    inputs: Inputs,
+    oracles: OracleResolver,
+    pedantic_solving: bool,
+) -> Result<WitnessStack<FieldElement>, NoirError> {
+    let default_executor = DefaultForeignCallBuilder::default()
+        .with_output(Vec::<u8>::new())
+        .with_mocks(false)
+        .build();
+    let mut foreign_call_executor = PluggableForeignCallExecutor::new(default_executor, oracles);
+
+    let initial_witness = program
+        .abi
+        .encode(inputs.as_input_map(), None)
+        .map_err(|e| {
+            NoirError::Execution(format!(
+                "Noir program execution failed when encoding inputs: {e}"
+            ))
+        })?;
+
+    execute(
+        program,
+        initial_witness,
+        &mut foreign_call_executor,
+        pedantic_solving,
+    )
+}
 
 #[cfg(test)]
 mod tests {
+    use super::execute;
+    use acir::native_types::WitnessMap;
+    use acir::FieldElement;
+    use log::debug;
+    use nargo::foreign_calls::DefaultForeignCallBuilder;
+    use nargo_toml::PackageSelection;
     use noirc_driver::CompileOptions;
+    use std::time::Instant;
 
     #[test]
     fn compile_noir() {
@@ -132,4 +508,133 @@ mod tests {
             super::compile("test_vectors/hello_world", settings).expect("Noir compilation failed.");
         assert_eq!(compile_result.warnings.len(), 0);
     }
+
+    #[test]
+    fn compile_noir_contract() {
+        let _ = env_logger::try_init();
+        let settings = CompileOptions::default();
+        let compile_result = super::compile_contract("test_vectors/counter_contract", settings)
+            .expect("Noir contract compilation failed.");
+        assert!(compile_result.contract.functions.len() > 1);
+        assert!(!compile_result.file_map.is_empty());
+    }
+
+    /// Not a strict speedup assertion (thread pool setup can dominate on a workspace this small,
+    /// and CI machines are noisy), but logs wall-clock parallel vs. serial parse time on a
+    /// many-module workspace so a regression that makes parallel parsing slower shows up in the
+    /// test output.
+    #[test]
+    fn logs_parallel_and_serial_parse_times_on_many_modules() {
+        let _ = env_logger::try_init();
+
+        let parallel_start = Instant::now();
+        super::compile("test_vectors/many_modules", CompileOptions::default())
+            .expect("Noir compilation failed.");
+        let parallel_elapsed = parallel_start.elapsed();
+
+        let serial_start = Instant::now();
+        super::compile_serial("test_vectors/many_modules", CompileOptions::default())
+            .expect("Noir compilation failed.");
+        let serial_elapsed = serial_start.elapsed();
+
+        debug!(
+            "parse_workspace: parallel={parallel_elapsed:?} serial={serial_elapsed:?} (workspace: test_vectors/many_modules)"
+        );
+    }
+
+    #[test]
+    fn generate_solidity_verifier_for_hello_world() {
+        let _ = env_logger::try_init();
+        let settings = CompileOptions::default();
+        let compile_result = super::compile("test_vectors/hello_world", settings)
+            .expect("Noir compilation failed.");
+
+        let bytecode = acir::bincode_serialize(&compile_result.program.bytecode)
+            .expect("failed to serialize bytecode");
+        let vk = crate::barretenberg_api::bbapi::get_ultra_honk_keccak_verification_key(&bytecode)
+            .expect("failed to compute verification key");
+
+        let contract = super::generate_solidity_verifier(&compile_result.program, &vk)
+            .expect("Solidity verifier generation failed.");
+        assert!(!contract.is_empty());
+    }
+
+    #[test]
+    fn generate_solidity_verifier_rejects_empty_vk() {
+        let _ = env_logger::try_init();
+        let settings = CompileOptions::default();
+        let compile_result = super::compile("test_vectors/hello_world", settings)
+            .expect("Noir compilation failed.");
+
+        assert!(super::generate_solidity_verifier(&compile_result.program, &[]).is_err());
+    }
+
+    #[test]
+    fn compile_workspace_by_name() {
+        let _ = env_logger::try_init();
+        let results = super::compile_workspace(
+            "test_vectors/workspace",
+            CompileOptions::default(),
+            PackageSelection::Selected("add_one".parse().unwrap()),
+        )
+        .expect("Noir compilation failed.");
+
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn compile_workspace_by_name_lists_available_packages_on_miss() {
+        let _ = env_logger::try_init();
+        let error = super::compile_workspace(
+            "test_vectors/workspace",
+            CompileOptions::default(),
+            PackageSelection::Selected("does_not_exist".parse().unwrap()),
+        )
+        .expect_err("compiling a nonexistent package should fail");
+
+        let message = error.to_string();
+        assert!(message.contains("add_one"));
+        assert!(message.contains("add_two"));
+    }
+
+    #[test]
+    fn compile_workspace_all_builds_every_binary_package() {
+        let _ = env_logger::try_init();
+        let results = super::compile_workspace(
+            "test_vectors/workspace",
+            CompileOptions::default(),
+            PackageSelection::All,
+        )
+        .expect("Noir compilation failed.");
+
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    fn execute_noir() {
+        let _ = env_logger::try_init();
+        let settings = CompileOptions::default();
+        let compile_result =
+            super::compile("test_vectors/hello_world", settings).expect("Noir compilation failed.");
+
+        // `hello_world` takes a private input `x` and a public input `y` (see
+        // `artifacts::tests::test_load_artifact`), which land in witnesses 0 and 1.
+        let mut inputs_map = WitnessMap::new();
+        inputs_map.insert(acir::native_types::Witness(0), FieldElement::from(1u128));
+        inputs_map.insert(acir::native_types::Witness(1), FieldElement::from(2u128));
+
+        let mut foreign_call_executor = DefaultForeignCallBuilder::default()
+            .with_output(Vec::<u8>::new())
+            .with_mocks(false)
+            .build();
+
+        let witness_stack = execute(
+            &compile_result.program,
+            inputs_map,
+            &mut foreign_call_executor,
+            false,
+        )
+        .expect("Noir execution failed.");
+        assert!(!witness_stack.is_empty());
+    }
 }
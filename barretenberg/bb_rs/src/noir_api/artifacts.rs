@@ -1,7 +1,7 @@
 use flate2::read::GzDecoder;
 use noirc_artifacts::program::ProgramArtifact;
 use std::fs::File;
-use std::io::Read;
+use std::io::{Error, ErrorKind, Read};
 use std::path::Path;
 
 pub fn load_artifact(path: impl AsRef<Path>) -> Result<ProgramArtifact, std::io::Error> {
@@ -63,6 +63,164 @@ fn save_compressed_binary(path: &Path, data: &[u8]) -> Result<(), std::io::Error
     Ok(())
 }
 
+/// Magic bytes identifying a versioned artifact container, chosen so they can't be confused with
+/// a gzip header (`\x1f\x8b`) or raw msgpack/bytecode content.
+const CONTAINER_MAGIC: [u8; 4] = *b"BBAC";
+
+/// The only container format version understood so far.
+const CONTAINER_VERSION: u8 = 1;
+
+/// Fixed-size header: magic (4) + version (1) + kind (1) + codec (1) + body length (4).
+const CONTAINER_HEADER_LEN: usize = 11;
+
+/// What kind of artifact a container holds.
+///
+/// This lets a reader tell a proof apart from a witness or verification key before trying to
+/// deserialize it, instead of relying on the caller to already know what the file contains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadKind {
+    Proof,
+    Witness,
+    VerificationKey,
+    /// The container magic was absent, so the bytes were read via the legacy raw/gz path with no
+    /// declared kind.
+    Unknown,
+}
+
+impl PayloadKind {
+    /// Encodes this kind for a container header. Fails for [`PayloadKind::Unknown`], which
+    /// exists only to report that a *read* file's kind was never recorded -- it isn't a kind a
+    /// container can be *written* with.
+    fn to_byte(self) -> Result<u8, std::io::Error> {
+        match self {
+            PayloadKind::Proof => Ok(0),
+            PayloadKind::Witness => Ok(1),
+            PayloadKind::VerificationKey => Ok(2),
+            PayloadKind::Unknown => Err(Error::new(
+                ErrorKind::InvalidInput,
+                "cannot write a container with PayloadKind::Unknown; pick a concrete kind",
+            )),
+        }
+    }
+
+    fn from_byte(byte: u8) -> Result<Self, std::io::Error> {
+        match byte {
+            0 => Ok(PayloadKind::Proof),
+            1 => Ok(PayloadKind::Witness),
+            2 => Ok(PayloadKind::VerificationKey),
+            other => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unknown container payload kind {}", other),
+            )),
+        }
+    }
+}
+
+/// The compression codec applied to a container's body.
+///
+/// Keeping this as a header field (rather than an out-of-band `.gz` extension convention) lets us
+/// add codecs later without inventing another extension to recognize.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Codec {
+    None,
+    Gzip,
+}
+
+impl Codec {
+    fn to_byte(self) -> u8 {
+        match self {
+            Codec::None => 0,
+            Codec::Gzip => 1,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Result<Self, std::io::Error> {
+        match byte {
+            0 => Ok(Codec::None),
+            1 => Ok(Codec::Gzip),
+            other => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unknown container codec {}", other),
+            )),
+        }
+    }
+}
+
+fn gzip_compress(data: &[u8]) -> Result<Vec<u8>, std::io::Error> {
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data)?;
+    encoder.finish()
+}
+
+fn gzip_decompress(data: &[u8]) -> Result<Vec<u8>, std::io::Error> {
+    let mut decoder = GzDecoder::new(data);
+    let mut decompressed = Vec::new();
+    decoder.read_to_end(&mut decompressed)?;
+    Ok(decompressed)
+}
+
+/// Writes `data` to `path` wrapped in a length-prefixed, magic-tagged container, recording its
+/// kind and compression codec in the header so a later reader can tell what it is before
+/// deserializing it.
+pub fn write_container(
+    path: impl AsRef<Path>,
+    kind: PayloadKind,
+    codec: Codec,
+    data: &[u8],
+) -> Result<(), std::io::Error> {
+    let body = match codec {
+        Codec::None => data.to_vec(),
+        Codec::Gzip => gzip_compress(data)?,
+    };
+
+    let mut framed = Vec::with_capacity(CONTAINER_HEADER_LEN + body.len());
+    framed.extend_from_slice(&CONTAINER_MAGIC);
+    framed.push(CONTAINER_VERSION);
+    framed.push(kind.to_byte()?);
+    framed.push(codec.to_byte());
+    framed.extend_from_slice(&(body.len() as u32).to_be_bytes());
+    framed.extend_from_slice(&body);
+
+    save_uncompressed_binary(path.as_ref(), &framed)
+}
+
+/// Reads a container written by [`write_container`], returning its declared kind and decoded
+/// body.
+///
+/// For backward compatibility, if the magic is absent the file is assumed to be a legacy artifact
+/// and is loaded via the existing raw/gz [`load_binary`] path, with [`PayloadKind::Unknown`]
+/// reported since no kind was ever recorded for it.
+pub fn read_container(path: impl AsRef<Path>) -> Result<(PayloadKind, Vec<u8>), std::io::Error> {
+    let raw = load_binary(path)?;
+    if raw.len() < CONTAINER_HEADER_LEN || raw[0..4] != CONTAINER_MAGIC {
+        return Ok((PayloadKind::Unknown, raw));
+    }
+
+    let version = raw[4];
+    if version != CONTAINER_VERSION {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("unsupported container version {}", version),
+        ));
+    }
+    let kind = PayloadKind::from_byte(raw[5])?;
+    let codec = Codec::from_byte(raw[6])?;
+    let body_len = u32::from_be_bytes([raw[7], raw[8], raw[9], raw[10]]) as usize;
+    let body = raw
+        .get(CONTAINER_HEADER_LEN..CONTAINER_HEADER_LEN + body_len)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidData, "container body shorter than declared length"))?;
+
+    let data = match codec {
+        Codec::None => body.to_vec(),
+        Codec::Gzip => gzip_decompress(body)?,
+    };
+    Ok((kind, data))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,4 +239,23 @@ mod tests {
         assert!(loaded_artifact.abi.parameters[1].is_public());
         assert_eq!(loaded_artifact.bytecode.functions.len(), 1);
     }
+
+    #[test]
+    fn test_write_container_rejects_unknown_payload_kind() {
+        let path = std::env::temp_dir().join("bb_rs_write_container_rejects_unknown.bin");
+        let result = write_container(&path, PayloadKind::Unknown, Codec::None, b"data");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_container_round_trip_preserves_kind_and_codec() {
+        let path = std::env::temp_dir().join("bb_rs_container_round_trip.bin");
+        let data = b"a proof's worth of bytes";
+
+        write_container(&path, PayloadKind::Proof, Codec::Gzip, data).unwrap();
+        let (kind, read_back) = read_container(&path).unwrap();
+
+        assert_eq!(kind, PayloadKind::Proof);
+        assert_eq!(read_back, data);
+    }
 }
@@ -0,0 +1,81 @@
+//! Keeps ACIR debug info (opcode -> source location) correct across transformations that shift
+//! opcode indices, such as backend-specific optimization run after compilation.
+//!
+//! Compilation captures each circuit's [`DebugInfo`] up front, in [`CompilationResult`](crate::noir_api::CompilationResult);
+//! whenever a later transformation produces an old -> new opcode-location map, pass both to
+//! [`remap_after_transform`] to rebuild it.
+
+use acir::circuit::opcodes::OpcodeLocation;
+use noirc_errors::debug_info::DebugInfo;
+use std::collections::BTreeMap;
+
+/// Rebuilds `debug_info.locations` after a transformation that maps each old opcode location to
+/// zero or more new ones.
+///
+/// A single old opcode can expand into several new ones (e.g. during optimization), so every
+/// source location attached to it is reinserted under *each* new location the map yields for
+/// that old index, not just the first. Old opcode locations the map has no entry for (already
+/// removed by the transformation) are dropped along with their source locations.
+pub fn remap_after_transform(
+    debug_info: &mut DebugInfo,
+    opcode_location_map: &BTreeMap<OpcodeLocation, Vec<OpcodeLocation>>,
+) {
+    let old_locations = std::mem::take(&mut debug_info.locations);
+    for (old_opcode_location, source_locations) in old_locations {
+        let Some(new_opcode_locations) = opcode_location_map.get(&old_opcode_location) else {
+            continue;
+        };
+        for new_opcode_location in new_opcode_locations {
+            debug_info
+                .locations
+                .insert(*new_opcode_location, source_locations.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::remap_after_transform;
+    use acir::circuit::opcodes::OpcodeLocation;
+    use noirc_driver::CompileOptions;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn remap_preserves_source_locations_through_a_splitting_transform() {
+        let _ = env_logger::try_init();
+        let settings = CompileOptions::default();
+        let compile_result = crate::noir_api::compile("test_vectors/hello_world", settings)
+            .expect("Noir compilation failed.");
+
+        let mut debug_info = compile_result
+            .debug_info
+            .into_iter()
+            .next()
+            .expect("compiled program should carry at least one circuit's debug info");
+        assert!(
+            !debug_info.locations.is_empty(),
+            "fixture circuit should have at least one opcode with a source location"
+        );
+
+        // Simulate a transformation that splits every opcode into two new ones.
+        let mut opcode_location_map = BTreeMap::new();
+        for (i, old_opcode_location) in debug_info.locations.keys().cloned().enumerate() {
+            let new_a = OpcodeLocation::Acir(i * 2);
+            let new_b = OpcodeLocation::Acir(i * 2 + 1);
+            opcode_location_map.insert(old_opcode_location, vec![new_a, new_b]);
+        }
+
+        let original_locations = debug_info.locations.clone();
+        remap_after_transform(&mut debug_info, &opcode_location_map);
+
+        for (old_opcode_location, source_locations) in &original_locations {
+            for new_opcode_location in &opcode_location_map[old_opcode_location] {
+                assert_eq!(
+                    debug_info.locations.get(new_opcode_location),
+                    Some(source_locations),
+                    "source locations should survive the transform under every new opcode location"
+                );
+            }
+        }
+    }
+}
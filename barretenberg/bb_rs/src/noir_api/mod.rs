@@ -1,9 +1,22 @@
 mod api;
 pub mod artifacts;
+pub mod debug_info;
+pub mod inputs;
+pub mod oracle;
+pub mod prove;
 
 // exports
-pub use api::{compile, NoirError};
+pub use api::{
+    compile, compile_contract, compile_serial, compile_workspace, execute, execute_with_inputs,
+    generate_solidity_verifier, CompilationResult, ContractCompilationResult, NoirError,
+};
+pub use nargo_toml::PackageSelection;
+pub use debug_info::remap_after_transform;
+pub use inputs::Inputs;
+pub use oracle::{OracleHandler, OracleResolver};
+pub use prove::{prove, verify, Backend};
 
 // re-export
 pub use acir::circuit::Program;
+pub use noirc_artifacts::contract::CompiledContract;
 pub use noirc_artifacts::program::ProgramArtifact;
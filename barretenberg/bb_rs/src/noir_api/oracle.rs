@@ -0,0 +1,89 @@
+use acir::brillig::{ForeignCallParam, ForeignCallResult};
+use acir::FieldElement;
+use acvm::pwg::ForeignCallWaitInfo;
+use nargo::foreign_calls::{DefaultForeignCallExecutor, ForeignCallExecutor};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A host-registered handler for a single named foreign call (an oracle or unconstrained
+/// function a Noir program delegates back to its embedder).
+///
+/// Inputs and outputs are the raw field elements `execute_program` passes across the foreign
+/// call boundary; callers that need structured values are expected to encode/decode them the
+/// same way the Noir program itself does.
+pub type OracleHandler =
+    Arc<dyn Fn(&[FieldElement]) -> Result<Vec<FieldElement>, String> + Send + Sync>;
+
+/// A registry of [`OracleHandler`]s keyed by foreign call name.
+///
+/// Pass this to [`crate::noir_api::execute_with_inputs`] so a mobile or embedded host can service whatever
+/// `std::println`-style debug output, RNG, or app-specific oracle a Noir program calls at
+/// runtime, instead of failing with no way for the embedder to respond.
+#[derive(Clone, Default)]
+pub struct OracleResolver {
+    handlers: HashMap<String, OracleHandler>,
+}
+
+impl OracleResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a handler for the named foreign call, replacing any existing handler for that
+    /// name.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        handler: impl Fn(&[FieldElement]) -> Result<Vec<FieldElement>, String> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.handlers.insert(name.into(), Arc::new(handler));
+        self
+    }
+}
+
+/// Wraps the default foreign call executor: a call with a handler in the caller-supplied
+/// [`OracleResolver`] goes to that handler, and every other call to Noir's built-in
+/// mocks/print/RNG support.
+pub(crate) struct PluggableForeignCallExecutor {
+    default: DefaultForeignCallExecutor<Vec<u8>>,
+    resolver: OracleResolver,
+}
+
+impl PluggableForeignCallExecutor {
+    pub(crate) fn new(default: DefaultForeignCallExecutor<Vec<u8>>, resolver: OracleResolver) -> Self {
+        Self { default, resolver }
+    }
+}
+
+impl ForeignCallExecutor<FieldElement> for PluggableForeignCallExecutor {
+    fn execute(
+        &mut self,
+        foreign_call: &ForeignCallWaitInfo<FieldElement>,
+    ) -> Result<ForeignCallResult<FieldElement>, nargo::errors::ForeignCallError> {
+        // A host handler registered under the call's name takes the call first; a call with no
+        // handler goes to Noir's own mocks/print/RNG handling.
+        match self.resolver.handlers.get(&foreign_call.function) {
+            None => self.default.execute(foreign_call),
+            Some(handler) => {
+                let name = foreign_call.function.clone();
+
+                let inputs: Vec<FieldElement> = foreign_call
+                    .inputs
+                    .iter()
+                    .flat_map(|value| value.fields())
+                    .collect();
+
+                let outputs = handler(&inputs).map_err(|message| {
+                    nargo::errors::ForeignCallError::NoHandler(format!(
+                        "foreign call `{}` failed: {}",
+                        name, message
+                    ))
+                })?;
+
+                Ok(ForeignCallResult {
+                    values: outputs.into_iter().map(ForeignCallParam::Single).collect(),
+                })
+            }
+        }
+    }
+}
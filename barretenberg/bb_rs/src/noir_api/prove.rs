@@ -0,0 +1,57 @@
+use crate::barretenberg_api::bbapi::CircuitProveResponse;
+use crate::noir_api::NoirError;
+use crate::{ultra_honk, ultra_honk_keccak, ultra_honk_keccak_zk};
+use acir::bincode_serialize;
+use acir::native_types::WitnessStack;
+use acir::FieldElement;
+use noirc_artifacts::program::ProgramArtifact;
+
+/// Which UltraHonk proving backend to target.
+///
+/// This determines both the oracle hash used inside the proof system and the shape of the
+/// resulting verifier: [`Backend::UltraHonk`] is cheapest to verify recursively, while the two
+/// Keccak variants produce a proof an EVM `Verifier.sol` contract can check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// Poseidon2-oracle UltraHonk.
+    UltraHonk,
+    /// Keccak-oracle UltraHonk, sized for an EVM/Solidity verifier.
+    UltraHonkKeccak,
+    /// Keccak-oracle UltraHonk with zero-knowledge enabled.
+    UltraHonkKeccakZk,
+}
+
+/// Proves `program` against `witness_stack`, marshalling both into the buffers the bb FFI
+/// expects and dispatching to the chosen [`Backend`].
+///
+/// This is the single place that ties compilation/execution output to the unsafe FFI layer, so a
+/// resource-constrained or mobile caller can go from a compiled circuit straight to a proof
+/// (bundled with its verification key) without shelling out to the WASM bindings or the Nargo
+/// CLI.
+pub fn prove(
+    program: &ProgramArtifact,
+    witness_stack: WitnessStack<FieldElement>,
+    backend: Backend,
+) -> Result<CircuitProveResponse, NoirError> {
+    let bytecode = bincode_serialize(&program.bytecode)
+        .map_err(|e| NoirError::Execution(format!("Failed to serialize bytecode: {e}")))?;
+    let witness = bincode_serialize(&witness_stack)
+        .map_err(|e| NoirError::Execution(format!("Failed to serialize witness: {e}")))?;
+
+    let result = match backend {
+        Backend::UltraHonk => ultra_honk::prove(&bytecode, &witness, &[]),
+        Backend::UltraHonkKeccak => ultra_honk_keccak::prove(&bytecode, &witness, &[]),
+        Backend::UltraHonkKeccakZk => ultra_honk_keccak_zk::prove(&bytecode, &witness, &[]),
+    };
+    result.map_err(|e| NoirError::Execution(e.to_string()))
+}
+
+/// Verifies a proof previously produced by [`prove`] with the same [`Backend`].
+pub fn verify(proof: CircuitProveResponse, backend: Backend) -> Result<bool, NoirError> {
+    let verified = match backend {
+        Backend::UltraHonk => ultra_honk::verify(proof),
+        Backend::UltraHonkKeccak => ultra_honk_keccak::verify(proof),
+        Backend::UltraHonkKeccakZk => ultra_honk_keccak_zk::verify(proof),
+    };
+    verified.map_err(|e| NoirError::Execution(e.to_string()))
+}
@@ -0,0 +1,142 @@
+//! `#[derive(ToInputValue)]`, generating an implementation of `zkuh_rs::noir_api::ToInputValue`
+//! for a struct with named fields, so callers building ACIR inputs for a large Noir circuit ABI
+//! don't have to assemble each nested struct by hand through [`zkuh_rs::noir_api::Inputs`],
+//! [`zkuh_rs::noir_api::PointInput`], and [`zkuh_rs::noir_api::VecInput`].
+//!
+//! Each field is converted with its own `to_input_value()` and inserted into the emitted
+//! `InputValue::Struct` keyed by the field's name (or, with `#[input(rename = "...")]`, the given
+//! Noir ABI parameter name instead). A field of type `Vec<T>` is wrapped in
+//! `zkuh_rs::noir_api::VecInput` first, since `Vec<T>` itself has no `ToInputValue` impl — only
+//! `VecInput<T>` does. A field of a nested `#[derive(ToInputValue)]`ed struct type is converted
+//! the same way as any other field, since the derived impl is just another `ToInputValue`.
+//!
+//! This crate only exports proc-macros; it has no other public API, matching the usual
+//! `*-derive` crate shape the `zkuh_rs::noir_api::ToInputValue` blanket impls are modeled after.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Field, Fields, Type};
+
+#[proc_macro_derive(ToInputValue, attributes(input))]
+pub fn derive_to_input_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return syn::Error::new_spanned(
+                    name,
+                    "ToInputValue can only be derived for structs with named fields",
+                )
+                .to_compile_error()
+                .into();
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(name, "ToInputValue can only be derived for structs")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let inserts = fields.iter().map(|field| {
+        let field_ident = field.ident.as_ref().expect("named field has an ident");
+        let key = field_rename(field).unwrap_or_else(|| field_ident.to_string());
+        let value_expr = if is_vec_type(&field.ty) {
+            quote! {
+                ::zkuh_rs::noir_api::ToInputValue::to_input_value(
+                    ::zkuh_rs::noir_api::VecInput::new(self.#field_ident),
+                )
+                .map_err(::std::convert::Into::into)?
+            }
+        } else {
+            quote! {
+                ::zkuh_rs::noir_api::ToInputValue::to_input_value(self.#field_ident)
+                    .map_err(::std::convert::Into::into)?
+            }
+        };
+        quote! {
+            map.insert(#key.to_string(), #value_expr);
+        }
+    });
+
+    let expanded = quote! {
+        impl ::zkuh_rs::noir_api::ToInputValue for #name {
+            type Error = ::zkuh_rs::noir_api::InputError;
+
+            fn to_input_value(
+                self,
+            ) -> ::std::result::Result<::zkuh_rs::noir_api::InputValue, Self::Error> {
+                let mut map = ::std::collections::BTreeMap::new();
+                #(#inserts)*
+                Ok(::zkuh_rs::noir_api::InputValue::Struct(map))
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// Whether `ty` is (syntactically) a `Vec<_>`, so its field should be wrapped in `VecInput`
+/// rather than converted directly.
+fn is_vec_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Vec"),
+        _ => false,
+    }
+}
+
+/// Reads a field's `#[input(rename = "...")]` attribute, if present.
+fn field_rename(field: &Field) -> Option<String> {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("input"))
+        .find_map(|attr| {
+            let mut renamed = None;
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    renamed = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                }
+                Ok(())
+            });
+            renamed
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn named_field(source: &str) -> Field {
+        let item: syn::ItemStruct = syn::parse_str(source).expect("failed to parse test struct");
+        match item.fields {
+            Fields::Named(fields) => fields.named.into_iter().next().expect("no fields"),
+            _ => panic!("expected named fields"),
+        }
+    }
+
+    #[test]
+    fn is_vec_type_recognizes_vec_fields() {
+        let field = named_field("struct S { f: Vec<u8> }");
+        assert!(is_vec_type(&field.ty));
+
+        let field = named_field("struct S { f: u8 }");
+        assert!(!is_vec_type(&field.ty));
+    }
+
+    #[test]
+    fn field_rename_reads_the_input_attribute() {
+        let field = named_field(r#"struct S { #[input(rename = "amount")] f: u64 }"#);
+        assert_eq!(field_rename(&field), Some("amount".to_string()));
+
+        let field = named_field("struct S { f: u64 }");
+        assert_eq!(field_rename(&field), None);
+    }
+}
@@ -30,6 +30,16 @@ impl InputError {
     }
 }
 
+// `PointInput`'s `ToInputValue::Error` is `Infallible`, since building a point from two field
+// elements that are already field elements can't fail. This lets callers that mix infallible and
+// fallible field conversions (e.g. in a `#[derive(ToInputValue)]`ed struct) unify on `InputError`
+// with a single `?`, rather than matching on `Infallible` by hand.
+impl From<Infallible> for InputError {
+    fn from(infallible: Infallible) -> Self {
+        match infallible {}
+    }
+}
+
 //------------------------ Inputs - Wrapper around InputMap -----------------------
 
 #[derive(Debug, Default)]
@@ -52,6 +62,23 @@ impl Inputs {
     pub fn as_input_map(&self) -> &InputMap {
         &self.inputs
     }
+
+    /// Converts `value` via [`ToInputValue`] and stores it under `name` as a single top-level
+    /// parameter, for ABI inputs that are a struct rather than a bare field (typically produced
+    /// by `#[derive(ToInputValue)]`, [`PointInput`], or [`VecInput`]).
+    pub fn add_struct<E>(
+        mut self,
+        name: impl AsRef<str>,
+        value: impl ToInputValue<Error = E>,
+    ) -> Result<Self, InputError>
+    where
+        InputError: From<E>,
+    {
+        let value = value.to_input_value()?;
+        let name = String::from(name.as_ref());
+        self.inputs.insert(name, value);
+        Ok(self)
+    }
 }
 
 //------------------------ ToInputValue - Helper trait -----------------------
@@ -62,11 +89,47 @@ pub trait ToInputValue {
 
 //------------------------ FieldInput - Wrapper around FieldElement -----------------------
 
+/// The BN254 scalar field modulus `acir::FieldElement` reduces against, as 32 big-endian bytes.
+/// Used by [`FieldInput::from_be_bytes_canonical`] to reject out-of-range values instead of
+/// silently wrapping them.
+const FIELD_MODULUS_BE: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
 #[derive(Clone, Copy, Debug)]
 pub struct FieldInput(FieldElement);
 
 impl FieldInput {
-    pub fn from_hex(hex_str: &str) -> Result<Self, InputError> {
+    /// Parses a `0x`-prefixed, 32-byte big-endian hex string, reducing modulo the field modulus
+    /// rather than rejecting an out-of-range value. Prefer [`Self::from_hex_canonical`] — this is
+    /// a deliberate, explicit opt-in for the lossy behavior (e.g. hashing arbitrary bytes down
+    /// into a field element), not the default.
+    pub fn from_hex_reduce(hex_str: &str) -> Result<Self, InputError> {
+        let bytes = Self::decode_hex_32(hex_str)?;
+        Ok(FieldInput(FieldElement::from_be_bytes_reduce(&bytes)))
+    }
+
+    /// Parses a `0x`-prefixed, 32-byte big-endian hex string, rejecting a value that is `>=` the
+    /// field modulus rather than reducing it. This is the check [`TryFrom<&str>`] uses, so
+    /// callers building ACIR witnesses can't accidentally submit a non-canonical field element.
+    pub fn from_hex_canonical(hex_str: &str) -> Result<Self, InputError> {
+        let bytes = Self::decode_hex_32(hex_str)?;
+        Self::from_be_bytes_canonical(&bytes)
+    }
+
+    /// Builds a field element from 32 big-endian bytes, rejecting values that are not strictly
+    /// less than the field modulus (see [`FIELD_MODULUS_BE`]), rather than reducing them.
+    pub fn from_be_bytes_canonical(bytes: &[u8; 32]) -> Result<Self, InputError> {
+        if bytes.as_slice() >= FIELD_MODULUS_BE.as_slice() {
+            return Err(InputError::InvalidFieldRepresentation {
+                reason: "value is not a canonical field element: value is greater than or equal to the field modulus".to_string(),
+            });
+        }
+        Ok(FieldInput(FieldElement::from_be_bytes_reduce(bytes)))
+    }
+
+    fn decode_hex_32(hex_str: &str) -> Result<[u8; 32], InputError> {
         if !hex_str.starts_with("0x") {
             return Err(InputError::InvalidFieldRepresentation {
                 reason: "Hex string must start with '0x'".to_string(),
@@ -84,11 +147,9 @@ impl FieldInput {
             hex::decode(&hex_str[2..]).map_err(|e| InputError::InvalidFieldRepresentation {
                 reason: format!("Failed to decode hex string: {e}"),
             })?;
-
-        // Audit -- is this secure? xxx_reduce applies a modulus operation, which may bias the result
-        // Should we not just throw an error if the value is not a canonical field element?
-        let val = FieldElement::from_be_bytes_reduce(&bytes);
-        Ok(FieldInput(val))
+        let mut array = [0u8; 32];
+        array.copy_from_slice(&bytes);
+        Ok(array)
     }
 
     pub fn from_decimal_str(dec_str: &str) -> Result<Self, InputError> {
@@ -117,7 +178,7 @@ impl TryFrom<&str> for FieldInput {
     type Error = InputError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        Self::from_hex(value)
+        Self::from_hex_canonical(value)
             .or_else(|e| Self::from_decimal_str(value).map_err(|e2| e.combine_reasons(&e2)))
     }
 }
@@ -287,6 +348,28 @@ mod test {
         );
     }
 
+    #[test]
+    fn non_canonical_field_element_is_rejected_by_default_but_reducible_explicitly() {
+        // Greater than the field modulus, so not a canonical representation.
+        let non_canonical_hex = "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+
+        let err = FieldInput::from_hex_canonical(non_canonical_hex).unwrap_err();
+        assert!(
+            matches!(&err, InputError::InvalidFieldRepresentation { reason }
+                if reason.contains("greater than or equal to the field modulus")
+            ),
+            "{err}"
+        );
+
+        // `TryFrom<&str>` goes through the same canonical check by default.
+        let err = FieldInput::try_from(non_canonical_hex).unwrap_err();
+        assert!(matches!(&err, InputError::InvalidFieldRepresentation { .. }), "{err}");
+
+        // The lossy reducing constructor is still available as an explicit opt-in.
+        FieldInput::from_hex_reduce(non_canonical_hex)
+            .expect("from_hex_reduce should still accept an out-of-range value");
+    }
+
     #[test]
     fn array_inputs() {
         let data = vec![
@@ -320,4 +403,18 @@ mod test {
         let val: InputValue = p1.into();
         assert!(matches!(val, InputValue::Struct(_)));
     }
+
+    #[test]
+    fn add_struct_stores_the_converted_value_under_the_given_name() {
+        let x_hex = "0x0ef59b243ee8819f82a6da86c875508d0e786c7453ef791beae4fcf0ae88c933";
+        let y_hex = "0x2a8a23239d91f7c2ff94c2b094bb91ff6751c03b76fd69a8770186628753ad4f";
+        let point = PointInput::new(x_hex, y_hex).expect("Failed to create point");
+
+        let inputs = Inputs::new()
+            .add_struct("pt", point)
+            .expect("Failed to add struct");
+
+        let stored = inputs.as_input_map().get("pt").expect("pt was not stored");
+        assert!(matches!(stored, InputValue::Struct(_)));
+    }
 }
@@ -6,7 +6,13 @@ mod inputs;
 pub use api::{compile, execute, CompilationResult, ExecutionResult, NoirError};
 pub use inputs::{FieldInput, InputError, Inputs, PointInput, ToInputValue, VecInput};
 
+// `ToInputValue` above names the trait; this re-export of the same name is the derive macro for
+// it, living in the macro namespace, so `#[derive(ToInputValue)]` and `impl ToInputValue for ...`
+// don't collide.
+pub use zkuh_rs_derive::ToInputValue;
+
 // re-export
 pub use acir::{circuit::Program, bincode_deserialize, bincode_serialize};
+pub use noirc_abi::input_parser::InputValue;
 pub use noirc_artifacts::program::ProgramArtifact;
 pub use noirc_driver::CompileOptions;
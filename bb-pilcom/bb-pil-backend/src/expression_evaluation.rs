@@ -11,6 +11,17 @@ use powdr_ast::{
     parsed::visitor::ExpressionVisitable,
 };
 use powdr_number::FieldElement;
+use thiserror::Error;
+
+/// An error turning a PIL [`AlgebraicExpression`] into a [`PolynomialExpression`] or computing its
+/// degree.
+#[derive(Debug, Error)]
+pub enum ExpressionError {
+    #[error("Pow exponent must be a constant, got {0}")]
+    NonConstantExponent(String),
+    #[error("Pow exponent must not be negative, got {0}")]
+    NegativeExponent(String),
+}
 
 // A polynomial expression is a flattened and simplified PIL expression
 // together with information about the placeholders.
@@ -26,11 +37,13 @@ pub struct PolynomialExpression {
     pub placeholders: HashMap<String, ExpressionPlaceholder>,
 }
 
-// A placeholder is a column or an alias.
+// A placeholder is a column, an alias, a verifier challenge, or a public input.
 #[derive(Debug, Clone)]
 pub enum ExpressionPlaceholder {
     Column(String),
     Alias(String),
+    Challenge(String),
+    Public(String),
 }
 
 impl PolynomialExpression {
@@ -39,6 +52,10 @@ impl PolynomialExpression {
         self.instantiate_with_handler(|placeholder| match placeholder {
             ExpressionPlaceholder::Column(col) => format!("static_cast<View>(in.get(C::{}))", col),
             ExpressionPlaceholder::Alias(alias) => format!("CView({})", alias),
+            ExpressionPlaceholder::Challenge(name) => format!("CView(params.{})", name),
+            ExpressionPlaceholder::Public(name) => {
+                format!("static_cast<View>(in.get_public(P::{}))", name)
+            }
         })
     }
 
@@ -47,6 +64,8 @@ impl PolynomialExpression {
         self.instantiate_with_handler(|placeholder| match placeholder {
             ExpressionPlaceholder::Column(col) => format!("in.get(C::{})", col),
             ExpressionPlaceholder::Alias(alias) => alias.clone(),
+            ExpressionPlaceholder::Challenge(name) => format!("params.{}", name),
+            ExpressionPlaceholder::Public(name) => format!("in.get_public(P::{})", name),
         })
     }
 
@@ -74,6 +93,30 @@ impl PolynomialExpression {
             })
             .collect::<HashSet<_>>()
     }
+
+    /// Get the verifier challenges referenced by the expression, so the surrounding generator can
+    /// declare the needed parameter fields on the relation's `RelationParameters`.
+    pub fn get_challenges(&self) -> HashSet<String> {
+        self.placeholders
+            .values()
+            .filter_map(|placeholder| match placeholder {
+                ExpressionPlaceholder::Challenge(name) => Some(name.clone()),
+                _ => None,
+            })
+            .collect::<HashSet<_>>()
+    }
+
+    /// Get the public inputs referenced by the expression, so the surrounding generator can wire
+    /// up the public-input argument list.
+    pub fn get_publics(&self) -> HashSet<String> {
+        self.placeholders
+            .values()
+            .filter_map(|placeholder| match placeholder {
+                ExpressionPlaceholder::Public(name) => Some(name.clone()),
+                _ => None,
+            })
+            .collect::<HashSet<_>>()
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -90,7 +133,7 @@ fn merge_maps(
 
 pub fn get_alias_expressions_in_order<F: FieldElement>(
     analyzed: &Analyzed<F>,
-) -> Vec<(String, PolynomialExpression)> {
+) -> Result<Vec<(String, PolynomialExpression)>, ExpressionError> {
     let alias_polys_in_order = analyzed
         .intermediate_polys_in_source_order()
         .iter()
@@ -112,18 +155,67 @@ pub fn get_alias_expressions_in_order<F: FieldElement>(
     alias_polys_in_order
         .iter()
         .map(|(sym, pil_expr)| {
-            let expr = compute_expression(pil_expr, &alias_names);
-            (sanitize_name(&sym.absolute_name), expr)
+            let expr = compute_expression(pil_expr, &alias_names)?;
+            Ok((sanitize_name(&sym.absolute_name), expr))
         })
-        .collect_vec()
+        .collect::<Result<Vec<_>, ExpressionError>>()
 }
 
-pub fn get_expression_degree<F: FieldElement>(expr: &AlgebraicExpression<F>) -> u64 {
-    match expr {
+pub fn get_expression_degree<F: FieldElement>(
+    expr: &AlgebraicExpression<F>,
+) -> Result<u64, ExpressionError> {
+    let degree = match expr {
         AlgebraicExpression::Reference(_poly) => 1,
         AlgebraicExpression::BinaryOperation(AlgebraicBinaryOperation { left, op, right }) => {
-            let lhs_degree = get_expression_degree(left);
-            let rhs_degree = get_expression_degree(right);
+            let lhs_degree = get_expression_degree(left)?;
+            match op {
+                AlgebraicBinaryOperator::Add => {
+                    std::cmp::max(lhs_degree, get_expression_degree(right)?)
+                }
+                AlgebraicBinaryOperator::Sub => {
+                    std::cmp::max(lhs_degree, get_expression_degree(right)?)
+                }
+                AlgebraicBinaryOperator::Mul => lhs_degree + get_expression_degree(right)?,
+                AlgebraicBinaryOperator::Pow => lhs_degree * pow_exponent(right)?,
+                _ => unimplemented!("{:?}", op),
+            }
+        }
+        AlgebraicExpression::UnaryOperation(AlgebraicUnaryOperation { op, expr: _ }) => match op {
+            AlgebraicUnaryOperator::Minus => get_expression_degree(expr)?,
+        },
+        AlgebraicExpression::PublicReference(_) => 1,
+        _ => 0,
+    };
+    Ok(degree)
+}
+
+/// Like [`get_expression_degree`], but a reference to an alias (intermediate polynomial) counts
+/// as the alias's own degree rather than defaulting to 1, matching the degree the reference will
+/// have once the alias is inlined. `alias_degrees` must already contain an entry for every alias
+/// `expr` can reach; see [`get_alias_degrees_in_order`].
+pub fn get_expression_degree_with_aliases<F: FieldElement>(
+    expr: &AlgebraicExpression<F>,
+    alias_names: &HashSet<String>,
+    alias_degrees: &HashMap<String, u64>,
+) -> u64 {
+    match expr {
+        AlgebraicExpression::Reference(poly) => {
+            let sanitized_name = sanitize_name(&poly.name);
+            if alias_names.contains(&sanitized_name) {
+                *alias_degrees
+                    .get(&sanitized_name)
+                    .expect("alias degree should have been precomputed in source order")
+            } else {
+                1
+            }
+        }
+        AlgebraicExpression::BinaryOperation(AlgebraicBinaryOperation { left, op, right }) => {
+            let lhs_degree = get_expression_degree_with_aliases(left, alias_names, alias_degrees);
+            let rhs_degree = get_expression_degree_with_aliases(right, alias_names, alias_degrees);
+            if matches!(op, AlgebraicBinaryOperator::Pow) {
+                return lhs_degree
+                    * pow_exponent(right).expect("Pow exponent must be a non-negative constant");
+            }
             match op {
                 AlgebraicBinaryOperator::Add => std::cmp::max(lhs_degree, rhs_degree),
                 AlgebraicBinaryOperator::Sub => std::cmp::max(lhs_degree, rhs_degree),
@@ -131,13 +223,35 @@ pub fn get_expression_degree<F: FieldElement>(expr: &AlgebraicExpression<F>) ->
                 _ => unimplemented!("{:?}", op),
             }
         }
-        AlgebraicExpression::UnaryOperation(AlgebraicUnaryOperation { op, expr: _ }) => match op {
-            AlgebraicUnaryOperator::Minus => get_expression_degree(expr),
-        },
+        AlgebraicExpression::UnaryOperation(AlgebraicUnaryOperation { op, expr: inner }) => {
+            match op {
+                AlgebraicUnaryOperator::Minus => {
+                    get_expression_degree_with_aliases(inner, alias_names, alias_degrees)
+                }
+            }
+        }
+        AlgebraicExpression::PublicReference(_) => 1,
         _ => 0,
     }
 }
 
+/// Computes the alias-aware degree (see [`get_expression_degree_with_aliases`]) of every
+/// intermediate polynomial, in source order so an alias that references an earlier alias can look
+/// its degree up directly.
+pub fn get_alias_degrees_in_order<F: FieldElement>(
+    analyzed: &Analyzed<F>,
+    alias_names: &HashSet<String>,
+) -> HashMap<String, u64> {
+    let mut alias_degrees = HashMap::new();
+    for (sym, exprs) in analyzed.intermediate_polys_in_source_order() {
+        let name = sanitize_name(&sym.absolute_name);
+        let degree =
+            get_expression_degree_with_aliases(exprs.first().unwrap(), alias_names, &alias_degrees);
+        alias_degrees.insert(name, degree);
+    }
+    alias_degrees
+}
+
 // We only try to remove parenthesis for ADD and MUL. This means
 // that only child_expr for these cases are handled.
 // Return true:
@@ -189,10 +303,24 @@ fn has_parent_priority<F: FieldElement>(
     }
 }
 
+/// The constant, non-negative exponent of a `Pow` node's right-hand side, as a `u64`. Returns an
+/// error rather than silently defaulting, since a non-constant or negative exponent can't be
+/// expanded into a fixed multiplication chain at codegen time.
+fn pow_exponent<F: FieldElement>(rhe: &AlgebraicExpression<F>) -> Result<u64, ExpressionError> {
+    match rhe {
+        AlgebraicExpression::Number(n) => Ok(n.to_degree()),
+        AlgebraicExpression::UnaryOperation(AlgebraicUnaryOperation {
+            op: AlgebraicUnaryOperator::Minus,
+            ..
+        }) => Err(ExpressionError::NegativeExponent(format!("{:?}", rhe))),
+        _ => Err(ExpressionError::NonConstantExponent(format!("{:?}", rhe))),
+    }
+}
+
 pub fn compute_expression<F: FieldElement>(
     current_expr: &AlgebraicExpression<F>,
     alias_names: &HashSet<String>,
-) -> PolynomialExpression {
+) -> Result<PolynomialExpression, ExpressionError> {
     compute_expression_(current_expr, alias_names, None)
 }
 
@@ -200,9 +328,9 @@ fn compute_expression_<F: FieldElement>(
     current_expr: &AlgebraicExpression<F>,
     alias_names: &HashSet<String>,
     parent_expr: Option<&AlgebraicExpression<F>>,
-) -> PolynomialExpression {
+) -> Result<PolynomialExpression, ExpressionError> {
     let has_parent_priority = has_parent_priority(parent_expr, current_expr);
-    match current_expr {
+    let expr = match current_expr {
         AlgebraicExpression::Number(n) => PolynomialExpression {
             pattern_with_placeholders: format_field(n),
             placeholders: HashMap::new(),
@@ -244,8 +372,8 @@ fn compute_expression_<F: FieldElement>(
             op,
             right: rhe,
         }) => {
-            let lhs = compute_expression_(lhe, alias_names, Some(current_expr));
-            let rhs = compute_expression_(rhe, alias_names, Some(current_expr));
+            let lhs = compute_expression_(lhe, alias_names, Some(current_expr))?;
+            let rhs = compute_expression_(rhe, alias_names, Some(current_expr))?;
 
             match op {
                 AlgebraicBinaryOperator::Add => {
@@ -298,6 +426,29 @@ fn compute_expression_<F: FieldElement>(
                         placeholders: merge_maps(lhs.placeholders, rhs.placeholders),
                     }
                 }
+                AlgebraicBinaryOperator::Pow => {
+                    let n = pow_exponent(rhe)?;
+                    if n == 0 {
+                        PolynomialExpression {
+                            pattern_with_placeholders: format_field(&F::one()),
+                            placeholders: HashMap::new(),
+                        }
+                    } else if n == 1 {
+                        lhs
+                    } else {
+                        let base = lhs.pattern_with_placeholders;
+                        let product = std::iter::repeat(base.as_str()).take(n as usize).join(" * ");
+                        let output = if has_parent_priority {
+                            format!("({})", product)
+                        } else {
+                            product
+                        };
+                        PolynomialExpression {
+                            pattern_with_placeholders: output,
+                            placeholders: lhs.placeholders,
+                        }
+                    }
+                }
                 _ => unimplemented!("{:?}", op),
             }
         }
@@ -306,16 +457,38 @@ fn compute_expression_<F: FieldElement>(
             expr: rec_expr,
         }) => match operator {
             AlgebraicUnaryOperator::Minus => {
-                let e = compute_expression_(rec_expr, alias_names, None);
+                let e = compute_expression_(rec_expr, alias_names, None)?;
                 PolynomialExpression {
                     pattern_with_placeholders: format!("-{}", e.pattern_with_placeholders),
                     placeholders: e.placeholders,
                 }
             }
         },
-        // Not currently used
-        AlgebraicExpression::PublicReference(_) => unimplemented!("{:?}", current_expr),
-        // Challenges are not being used in our current pil construction
-        AlgebraicExpression::Challenge(_) => unimplemented!("{:?}", current_expr),
-    }
+        AlgebraicExpression::PublicReference(name) => {
+            let sanitized_name = sanitize_name(name);
+            PolynomialExpression {
+                pattern_with_placeholders: format!("{{{}}}", sanitized_name),
+                placeholders: {
+                    let mut map = HashMap::new();
+                    map.insert(
+                        sanitized_name.clone(),
+                        ExpressionPlaceholder::Public(sanitized_name),
+                    );
+                    map
+                },
+            }
+        }
+        AlgebraicExpression::Challenge(challenge) => {
+            let name = format!("challenge_{}", challenge.id);
+            PolynomialExpression {
+                pattern_with_placeholders: format!("{{{}}}", name),
+                placeholders: {
+                    let mut map = HashMap::new();
+                    map.insert(name.clone(), ExpressionPlaceholder::Challenge(name));
+                    map
+                },
+            }
+        }
+    };
+    Ok(expr)
 }
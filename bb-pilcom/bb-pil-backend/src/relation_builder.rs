@@ -1,6 +1,8 @@
 use itertools::Itertools;
 use powdr_ast::analyzed::AlgebraicBinaryOperation;
+use powdr_ast::analyzed::AlgebraicBinaryOperator;
 use powdr_ast::analyzed::AlgebraicUnaryOperation;
+use powdr_ast::analyzed::AlgebraicUnaryOperator;
 use powdr_ast::analyzed::Analyzed;
 use powdr_ast::analyzed::Identity;
 use powdr_ast::analyzed::{AlgebraicExpression, IdentityKind};
@@ -15,10 +17,14 @@ use handlebars::Handlebars;
 use serde_json::json;
 
 use crate::expression_evaluation::compute_expression;
+use crate::expression_evaluation::get_alias_degrees_in_order;
 use crate::expression_evaluation::get_alias_expressions_in_order;
 use crate::expression_evaluation::get_expression_degree;
+use crate::expression_evaluation::ExpressionError;
+use crate::expression_evaluation::ExpressionPlaceholder;
 use crate::expression_evaluation::PolynomialExpression;
 use crate::file_writer::BBFiles;
+use crate::utils::sanitize_name;
 use crate::utils::snake_case;
 
 /// Each created bb Identity is passed around with its degree so as needs to be manually
@@ -28,6 +34,11 @@ pub struct BBIdentity {
     pub original_id: u64,
     pub expression: PolynomialExpression,
     pub label: Option<String>,
+    /// The subrelation's degree, when it's already known at construction time (e.g. for the
+    /// auxiliary relations [`create_lookup_relations`] synthesizes, which have no pil identity of
+    /// their own to look up a degree for). `None` falls back to the existing per-file degree
+    /// lookup in [`RelationBuilder::create_relations`].
+    pub degree: Option<u64>,
 }
 
 pub trait RelationBuilder {
@@ -39,11 +50,19 @@ pub trait RelationBuilder {
     /// Relation output is passed back to the caller as the prover requires both:
     /// - The shifted polys
     /// - The names of the relations files created
+    /// `max_subrelation_degree` bounds the degree of every emitted subrelation; pass `None` to
+    /// skip degree capping entirely (the previous behaviour). See [`create_identities`].
+    ///
+    /// # Errors
+    /// Returns [`ExpressionError`] if any pil identity contains a malformed `Pow` exponent (one
+    /// that isn't a non-negative constant) — such an identity can't be expanded into a fixed
+    /// multiplication chain at codegen time.
     fn create_relations<F: FieldElement>(
         &self,
         root_name: &str,
         analyzed: &Analyzed<F>,
-    ) -> Vec<String>;
+        max_subrelation_degree: Option<u64>,
+    ) -> Result<Vec<String>, ExpressionError>;
 
     /// Create Relation
     ///
@@ -60,9 +79,10 @@ pub trait RelationBuilder {
         name: &str,
         identities: &[BBIdentity],
         subrelation_lengths: &[u64],
-        skippable_if: &Option<BBIdentity>,
+        skippable_conditions: &[(String, BBIdentity, Vec<(String, PolynomialExpression)>)],
         alias_polys_in_order: &Vec<(String, PolynomialExpression)>,
-        alias_polys_in_skippable: &Vec<(String, PolynomialExpression)>,
+        auxiliary_witness_columns: &[String],
+        auxiliary_shifted_columns: &[String],
     );
 }
 
@@ -71,7 +91,8 @@ impl RelationBuilder for BBFiles {
         &self,
         file_name: &str,
         analyzed: &Analyzed<F>,
-    ) -> Vec<String> {
+        max_subrelation_degree: Option<u64>,
+    ) -> Result<Vec<String>, ExpressionError> {
         // It is easier to compute the degree of the expressions once the pol aliases are inlined.
         // Vector will be (identity id, degree).
         println!("Computing degrees...");
@@ -79,25 +100,26 @@ impl RelationBuilder for BBFiles {
             .identities_with_inlined_intermediate_polynomials()
             .iter()
             .sorted_by_key(|id| id.id)
-            .filter_map(|id| {
-                if id.kind != IdentityKind::Polynomial {
-                    None
-                } else {
-                    // It is strange that we use "selector" here, but that seems to be what gives you the expression.
-                    let expr = id.left.selector.as_ref().unwrap();
-                    Some((id.id, get_expression_degree(expr)))
-                }
+            .filter(|id| id.kind == IdentityKind::Polynomial)
+            .map(|id| {
+                // It is strange that we use "selector" here, but that seems to be what gives you the expression.
+                let expr = id.left.selector.as_ref().unwrap();
+                Ok((id.id, get_expression_degree(expr)?))
             })
-            .collect_vec();
+            .collect::<Result<Vec<_>, ExpressionError>>()?;
 
         // These expressions have sanitized names like: constants_NOTE_HASH_TREE_HEIGHT.
         println!("Computing alias expressions in order...");
-        let alias_expressions_in_order = get_alias_expressions_in_order(analyzed);
+        let alias_expressions_in_order = get_alias_expressions_in_order(analyzed)?;
         let alias_names = alias_expressions_in_order
             .iter()
             .map(|(name, _)| name.clone())
             .collect::<HashSet<_>>();
 
+        // Needed so a subtree's degree accounts for an alias's own expression instead of
+        // defaulting to 1, once `max_subrelation_degree` is given and degree capping runs.
+        let alias_degrees = get_alias_degrees_in_order(analyzed, &alias_names);
+
         // These identities' terminal objects are either fields, columns, or alias expressions.
         let mut analyzed_identities = analyzed.identities.clone();
         analyzed_identities.sort_by(|a, b| a.id.cmp(&b.id));
@@ -119,8 +141,15 @@ impl RelationBuilder for BBFiles {
             println!("Creating identities for relation: {}", relation_name);
             let IdentitiesOutput {
                 identities,
-                skippable_if,
-            } = create_identities(analyzed_idents, &alias_names);
+                skippable_conditions,
+                auxiliary_witness_columns,
+                auxiliary_shifted_columns,
+            } = create_identities(
+                analyzed_idents,
+                &alias_names,
+                &alias_degrees,
+                max_subrelation_degree,
+            )?;
 
             // Aliases used in the identities in this file.
             let filtered_aliases = get_transitive_aliases_for_identities(
@@ -128,15 +157,20 @@ impl RelationBuilder for BBFiles {
                 &alias_expressions_in_order,
             );
 
-            let filtered_subrelation_lengths = all_degrees
+            // Length is degree + 1. Auxiliary identities (e.g. from a lookup) already know their
+            // own degree; ordinary pil identities look theirs up by original id in `all_degrees`.
+            let filtered_subrelation_lengths = identities
                 .iter()
-                .filter(|(degree_id, _)| {
-                    identities
-                        .iter()
-                        .any(|id_other| id_other.original_id == *degree_id)
+                .map(|identity| {
+                    let degree = identity.degree.unwrap_or_else(|| {
+                        all_degrees
+                            .iter()
+                            .find(|(degree_id, _)| *degree_id == identity.original_id)
+                            .map(|(_, degree)| *degree)
+                            .expect("a polynomial identity's degree should have been precomputed")
+                    });
+                    degree + 1
                 })
-                // Length is degree + 1.
-                .map(|(_, degree)| *degree + 1)
                 .collect_vec();
 
             let used_alias_defs_in_order = alias_expressions_in_order
@@ -144,27 +178,34 @@ impl RelationBuilder for BBFiles {
                 .filter(|(name, _)| filtered_aliases.contains(name))
                 .cloned()
                 .collect_vec();
-            let used_alias_defs_in_skippable = skippable_if
-                .as_ref()
-                .map(|id| {
-                    let transitive_aliases =
-                        get_transitive_aliases_for_identities(&[id], &alias_expressions_in_order);
-                    alias_expressions_in_order
+            // Each condition's transitive alias set is computed individually (rather than once
+            // over their union) so a condition only pulls in the alias definitions it actually
+            // uses.
+            let skippable_conditions_with_aliases = skippable_conditions
+                .into_iter()
+                .map(|(name, identity)| {
+                    let transitive_aliases = get_transitive_aliases_for_identities(
+                        &[&identity],
+                        &alias_expressions_in_order,
+                    );
+                    let alias_defs = alias_expressions_in_order
                         .iter()
                         .filter(|(name, _)| transitive_aliases.contains(name))
                         .cloned()
-                        .collect_vec()
+                        .collect_vec();
+                    (name, identity, alias_defs)
                 })
-                .unwrap_or_default();
+                .collect_vec();
 
             self.create_relation(
                 file_name,
                 relation_name,
                 &identities,
                 &filtered_subrelation_lengths,
-                &skippable_if,
+                &skippable_conditions_with_aliases,
                 &used_alias_defs_in_order,
-                &used_alias_defs_in_skippable,
+                &auxiliary_witness_columns,
+                &auxiliary_shifted_columns,
             );
         }
 
@@ -199,7 +240,7 @@ impl RelationBuilder for BBFiles {
 
         relations.sort();
 
-        relations
+        Ok(relations)
     }
 
     fn create_relation(
@@ -208,9 +249,10 @@ impl RelationBuilder for BBFiles {
         name: &str,
         identities: &[BBIdentity],
         subrelation_lengths: &[u64],
-        skippable_if: &Option<BBIdentity>,
+        skippable_conditions: &[(String, BBIdentity, Vec<(String, PolynomialExpression)>)],
         alias_defs_in_order: &Vec<(String, PolynomialExpression)>,
-        alias_defs_in_skippable: &Vec<(String, PolynomialExpression)>,
+        auxiliary_witness_columns: &[String],
+        auxiliary_shifted_columns: &[String],
     ) {
         let mut handlebars = Handlebars::new();
         handlebars.register_escape_fn(|s| s.to_string()); // No escaping
@@ -241,18 +283,35 @@ impl RelationBuilder for BBFiles {
                     "expr": expr.instantiate(),
                 })
             }).collect_vec(),
-            "skippable_if": skippable_if.as_ref().map(|id|
-                // Skippable does not use `View`.
-                id.expression.instantiate()),
-            "subrelation_lengths": subrelation_lengths,
-            "labels": sorted_labels,
-            "skippable_alias_defs": alias_defs_in_skippable.iter().map(|(name, expr)| {
+            // Named skip predicates for this relation (see `IdentitiesOutput::skippable_conditions`).
+            // The generated `skip` function ORs them together: the relation may be skipped in any
+            // row region covered by at least one condition, since each condition is written to
+            // describe a distinct, disjoint region (e.g. `active` vs. `padding`) rather than a
+            // constraint that must jointly hold.
+            "skippable_conditions": skippable_conditions.iter().map(|(name, id, alias_defs)| {
                 json!({
                     "name": name,
-                    // Aliases do not use `View`.
-                    "expr": expr.instantiate(),
+                    // Skippable does not use `View`.
+                    "expr": id.expression.instantiate(),
+                    "alias_defs": alias_defs.iter().map(|(name, expr)| {
+                        json!({
+                            "name": name,
+                            // Aliases do not use `View`.
+                            "expr": expr.instantiate(),
+                        })
+                    }).collect_vec(),
                 })
             }).collect_vec(),
+            "subrelation_lengths": subrelation_lengths,
+            "labels": sorted_labels,
+            // Witness columns synthesized by this file's identities rather than declared in pil:
+            // LogUp lookup/permutation columns (`inv_f`/`inv_t`/`m`/`acc`, see
+            // `create_lookup_relations`) and degree-capping helper columns (see
+            // `cap_expression`). The flavor/trace builder needs to commit to all of them;
+            // `auxiliary_shifted_columns` is the subset whose `next` value is referenced,
+            // meant to be unioned with `get_shifted_polys`'s output.
+            "auxiliary_witness_columns": auxiliary_witness_columns,
+            "auxiliary_shifted_columns": auxiliary_shifted_columns,
         });
 
         handlebars
@@ -361,63 +420,554 @@ fn group_relations_per_file<F: FieldElement>(
     })
 }
 
+/// The result of turning a single `Polynomial` pil identity into a [`BBIdentity`]: the identity
+/// itself, plus anything [`cap_expression`] had to synthesize to keep it within
+/// `max_subrelation_degree`.
+struct CreatedIdentity {
+    identity: BBIdentity,
+    extra_identities: Vec<BBIdentity>,
+    witness_columns: Vec<String>,
+    shifted_columns: Vec<String>,
+}
+
 fn create_identity<F: FieldElement>(
     pil_identity: &Identity<AlgebraicExpression<F>>,
     alias_names: &HashSet<String>,
-) -> Option<BBIdentity> {
+    alias_degrees: &HashMap<String, u64>,
+    max_subrelation_degree: Option<u64>,
+    degree_cap_counter: &mut usize,
+) -> Result<Option<CreatedIdentity>, ExpressionError> {
     // We want to read the types of operators and then create the appropriate code
-    if let Some(expr) = &pil_identity.left.selector {
-        let poly_expr = compute_expression(expr, alias_names);
-        Some(BBIdentity {
+    let Some(expr) = pil_identity.left.selector.as_ref() else {
+        return Ok(None);
+    };
+
+    let Some(max_degree) = max_subrelation_degree else {
+        return Ok(Some(CreatedIdentity {
+            identity: BBIdentity {
+                original_id: pil_identity.id,
+                expression: compute_expression(expr, alias_names)?,
+                label: pil_identity.attribute.clone(),
+                degree: None,
+            },
+            extra_identities: Vec::new(),
+            witness_columns: Vec::new(),
+            shifted_columns: Vec::new(),
+        }));
+    };
+
+    let mut extra_identities = Vec::new();
+    let mut witness_columns = Vec::new();
+    let mut shifted_columns = Vec::new();
+    let capped = cap_expression(
+        expr,
+        alias_names,
+        alias_degrees,
+        max_degree,
+        pil_identity.id,
+        degree_cap_counter,
+        &mut extra_identities,
+        &mut witness_columns,
+        &mut shifted_columns,
+    )?;
+
+    Ok(Some(CreatedIdentity {
+        identity: BBIdentity {
             original_id: pil_identity.id,
-            expression: poly_expr,
+            expression: capped.expr,
             label: pil_identity.attribute.clone(),
-        })
-    } else {
-        None
-    }
+            degree: Some(capped.degree),
+        },
+        extra_identities,
+        witness_columns,
+        shifted_columns,
+    }))
 }
 
 pub struct IdentitiesOutput {
-    identities: Vec<BBIdentity>,
-    skippable_if: Option<BBIdentity>,
+    pub identities: Vec<BBIdentity>,
+    /// Named skip predicates found in this file, one per distinct `skippable_if` attribute,
+    /// keyed by the part of the label after `skippable_if` (e.g. `skippable_if_active` and
+    /// `skippable_if_padding` yield the keys `"active"` and `"padding"`; a bare `skippable_if`
+    /// keeps that whole label as its own key). A relation can carry more than one of these so it
+    /// can express distinct skip rules for disjoint row regions — see [`create_relation`] for how
+    /// they're combined.
+    pub skippable_conditions: Vec<(String, BBIdentity)>,
+    /// Witness columns synthesized rather than declared in pil: [`create_lookup_relations`]'s
+    /// inverse/multiplicity/accumulator columns, and [`cap_expression`]'s degree-capping
+    /// helper columns. The flavor/trace builder needs to commit to all of them.
+    pub auxiliary_witness_columns: Vec<String>,
+    /// The subset of `auxiliary_witness_columns` whose `next` value is referenced by a generated
+    /// relation, meant to be unioned with the output of [`get_shifted_polys`] for the ordinary
+    /// pil identities in the same file.
+    pub auxiliary_shifted_columns: Vec<String>,
 }
 
+/// The part of a `skippable_if...` attribute label after the `skippable_if` prefix, used as the
+/// condition's name; `None` if `label` isn't a skip predicate at all. A bare `skippable_if`
+/// yields `Some("skippable_if")` (there's no suffix to strip), while `skippable_if_active` yields
+/// `Some("active")`.
+fn skippable_condition_name(label: &str) -> Option<String> {
+    if label == "skippable_if" {
+        Some(label.to_string())
+    } else {
+        label.strip_prefix("skippable_if_").map(str::to_string)
+    }
+}
+
+/// `max_subrelation_degree`, when given, bounds the degree of every emitted `Polynomial`
+/// subrelation via [`cap_expression`] — see that function for how the bound is enforced.
+/// `Plookup`/`Permutation` identities are expressed by [`create_lookup_relations`] at a fixed,
+/// already-small degree and aren't subject to capping.
+///
+/// # Errors
+/// Returns [`ExpressionError`] if any identity in `identities` contains a malformed `Pow`
+/// exponent (one that isn't a non-negative constant).
 pub(crate) fn create_identities<F: FieldElement>(
     identities: &[Identity<AlgebraicExpression<F>>],
     alias_names: &HashSet<String>,
-) -> IdentitiesOutput {
-    // We only want the expressions for now
-    // When we have a poly type, we only need the left side of it since they are normalized to `left = 0`.
-    let ids = identities
+    alias_degrees: &HashMap<String, u64>,
+    max_subrelation_degree: Option<u64>,
+) -> Result<IdentitiesOutput, ExpressionError> {
+    let mut identities_out = Vec::new();
+    let mut skippable_conditions: Vec<(String, BBIdentity)> = Vec::new();
+    let mut auxiliary_witness_columns = Vec::new();
+    let mut auxiliary_shifted_columns = Vec::new();
+    let mut degree_cap_counter = 0usize;
+
+    for pil_identity in identities.iter() {
+        match pil_identity.kind {
+            IdentityKind::Polynomial => {
+                let Some(created) = create_identity(
+                    pil_identity,
+                    alias_names,
+                    alias_degrees,
+                    max_subrelation_degree,
+                    &mut degree_cap_counter,
+                )?
+                else {
+                    continue;
+                };
+
+                identities_out.extend(created.extra_identities);
+                auxiliary_witness_columns.extend(created.witness_columns);
+                auxiliary_shifted_columns.extend(created.shifted_columns);
+
+                let skip_name = created
+                    .identity
+                    .label
+                    .as_deref()
+                    .and_then(skippable_condition_name);
+                if let Some(skip_name) = skip_name {
+                    assert!(
+                        !skippable_conditions.iter().any(|(name, _)| *name == skip_name),
+                        "duplicate skippable_if condition {skip_name:?}"
+                    );
+                    skippable_conditions.push((skip_name, created.identity));
+                } else {
+                    identities_out.push(created.identity);
+                }
+            }
+            IdentityKind::Plookup | IdentityKind::Permutation => {
+                if let Some(lookup) = create_lookup_relations(pil_identity, alias_names)? {
+                    identities_out.extend(lookup.identities);
+                    auxiliary_witness_columns.extend(lookup.witness_columns);
+                    auxiliary_shifted_columns.extend(lookup.shifted_columns);
+                }
+            }
+            // Connect identities (copy constraints) aren't expressed as sumcheck relations.
+            _ => {}
+        }
+    }
+
+    Ok(IdentitiesOutput {
+        identities: identities_out,
+        skippable_conditions,
+        auxiliary_witness_columns,
+        auxiliary_shifted_columns,
+    })
+}
+
+/// The relations and auxiliary witness columns [`create_lookup_relations`] synthesizes for a
+/// single lookup (or permutation) identity.
+struct LookupRelations {
+    identities: Vec<BBIdentity>,
+    witness_columns: Vec<String>,
+    shifted_columns: Vec<String>,
+}
+
+/// Generates sumcheck-compatible log-derivative (LogUp) relations for a `Plookup`/`Permutation`
+/// identity, so PIL lookup arguments reach the generated bberg relation files instead of being
+/// silently dropped by [`create_identities`].
+///
+/// For a lookup asserting that the selected columns `f_1..f_k` (active where selector `s_f = 1`)
+/// appear in table columns `t_1..t_k`: each side is folded into a single value with a verifier
+/// challenge `β` (`f = Σ f_i·β^(i-1)`, `t` likewise). This introduces four witness columns —
+/// `inv_f`, `inv_t` (the inverse of `γ + f` and `γ + t`), `m` (the table's claimed multiplicity),
+/// and `acc` (a running accumulator) — and four relations:
+///  - `inv_f·(γ + f) - s_f = 0` (degree 2): `inv_f` really is `1 / (γ + f)` wherever `s_f = 1`.
+///  - `inv_t·(γ + t) - m = 0` (degree 2): likewise for the table side, weighted by multiplicity.
+///  - `acc' - acc - (inv_f - inv_t) = 0` (degree 1): `acc` accumulates the difference every row.
+///  - `acc = 0` (degree 1), labeled so the caller can pin it to the last row: the accumulator
+///    must return to zero, which holds iff every `f` value with `s_f = 1` is matched by table
+///    multiplicity.
+///
+/// Returns `Ok(None)` if the identity has no selector on its lookup side (nothing to fold).
+///
+/// # Errors
+/// Returns [`ExpressionError`] if the selector or any folded term contains a malformed `Pow`
+/// exponent.
+fn create_lookup_relations<F: FieldElement>(
+    pil_identity: &Identity<AlgebraicExpression<F>>,
+    alias_names: &HashSet<String>,
+) -> Result<Option<LookupRelations>, ExpressionError> {
+    let Some(selector_expr) = pil_identity.left.selector.as_ref() else {
+        return Ok(None);
+    };
+    let s_f = compute_expression(selector_expr, alias_names)?;
+
+    let f_terms = pil_identity
+        .left
+        .expressions
+        .iter()
+        .map(|expr| compute_expression(expr, alias_names))
+        .collect::<Result<Vec<_>, ExpressionError>>()?;
+    let t_terms = pil_identity
+        .right
+        .expressions
         .iter()
-        .filter(|identity| identity.kind == IdentityKind::Polynomial)
-        .collect::<Vec<_>>();
+        .map(|expr| compute_expression(expr, alias_names))
+        .collect::<Result<Vec<_>, ExpressionError>>()?;
+
+    let f = fold_with_challenge(&f_terms, "beta");
+    let t = fold_with_challenge(&t_terms, "beta");
+    let gamma = raw_expression("gamma");
+
+    let base = format!("lookup_{}", pil_identity.id);
+    let inv_f = format!("{base}_inv_f");
+    let inv_t = format!("{base}_inv_t");
+    let multiplicity = format!("{base}_m");
+    let accumulator = format!("{base}_acc");
+
+    let inv_f_correctness = BBIdentity {
+        original_id: pil_identity.id,
+        expression: sub_expr(
+            &mul_expr(&column_expression(&inv_f), &add_expr(&gamma, &f)),
+            &s_f,
+        ),
+        label: Some(format!("{base}_inv_f_correctness")),
+        degree: Some(2),
+    };
+    let inv_t_correctness = BBIdentity {
+        original_id: pil_identity.id,
+        expression: sub_expr(
+            &mul_expr(&column_expression(&inv_t), &add_expr(&gamma, &t)),
+            &column_expression(&multiplicity),
+        ),
+        label: Some(format!("{base}_inv_t_correctness")),
+        degree: Some(2),
+    };
+    let running_accumulator = BBIdentity {
+        original_id: pil_identity.id,
+        expression: sub_expr(
+            &sub_expr(
+                &shifted_column_expression(&accumulator),
+                &column_expression(&accumulator),
+            ),
+            &sub_expr(&column_expression(&inv_f), &column_expression(&inv_t)),
+        ),
+        label: Some(format!("{base}_accumulator")),
+        degree: Some(1),
+    };
+    let boundary = BBIdentity {
+        original_id: pil_identity.id,
+        expression: column_expression(&accumulator),
+        label: Some(format!("{base}_boundary")),
+        degree: Some(1),
+    };
+
+    Ok(Some(LookupRelations {
+        identities: vec![
+            inv_f_correctness,
+            inv_t_correctness,
+            running_accumulator,
+            boundary,
+        ],
+        witness_columns: vec![inv_f, inv_t, multiplicity, accumulator.clone()],
+        shifted_columns: vec![accumulator],
+    }))
+}
+
+/// A witness-column reference, e.g. `{inv_f}` with its own placeholder, the same shape
+/// [`compute_expression`] produces for an ordinary pil column reference.
+fn column_expression(name: &str) -> PolynomialExpression {
+    PolynomialExpression {
+        pattern_with_placeholders: format!("{{{name}}}"),
+        placeholders: HashMap::from([(
+            name.to_string(),
+            ExpressionPlaceholder::Column(name.to_string()),
+        )]),
+    }
+}
 
-    let mut identities = Vec::new();
-    let mut skippable_if_identity = None;
+/// A reference to the `next` row of a witness column, mirroring how [`compute_expression`]
+/// renders a shifted pil reference.
+fn shifted_column_expression(name: &str) -> PolynomialExpression {
+    column_expression(&format!("{name}_shift"))
+}
 
-    for pil_identity in ids.iter() {
-        let bb_identity = create_identity(&pil_identity, alias_names).unwrap();
+/// Text that isn't a column or alias placeholder, e.g. a challenge name the relation's verifier
+/// parameters already expose under that name.
+fn raw_expression(text: &str) -> PolynomialExpression {
+    PolynomialExpression {
+        pattern_with_placeholders: text.to_string(),
+        placeholders: HashMap::new(),
+    }
+}
 
-        if bb_identity
-            .label
-            .clone()
-            .is_some_and(|l| l == "skippable_if")
-        {
-            assert!(skippable_if_identity.is_none());
-            skippable_if_identity = Some(bb_identity);
-        } else {
-            identities.push(bb_identity);
+fn binary_expression(lhs: &PolynomialExpression, op: &str, rhs: &PolynomialExpression) -> PolynomialExpression {
+    let mut placeholders = lhs.placeholders.clone();
+    placeholders.extend(rhs.placeholders.clone());
+    PolynomialExpression {
+        pattern_with_placeholders: format!(
+            "({} {} {})",
+            lhs.pattern_with_placeholders, op, rhs.pattern_with_placeholders
+        ),
+        placeholders,
+    }
+}
+
+fn add_expr(lhs: &PolynomialExpression, rhs: &PolynomialExpression) -> PolynomialExpression {
+    binary_expression(lhs, "+", rhs)
+}
+
+fn sub_expr(lhs: &PolynomialExpression, rhs: &PolynomialExpression) -> PolynomialExpression {
+    binary_expression(lhs, "-", rhs)
+}
+
+fn mul_expr(lhs: &PolynomialExpression, rhs: &PolynomialExpression) -> PolynomialExpression {
+    binary_expression(lhs, "*", rhs)
+}
+
+/// Folds `terms` into `terms[0] + terms[1]·challenge + terms[2]·challenge^2 + ...`, the
+/// random-linear-combination trick used to turn a `k`-column lookup into a single-value one.
+fn fold_with_challenge(terms: &[PolynomialExpression], challenge_name: &str) -> PolynomialExpression {
+    let mut terms = terms.iter();
+    let Some(first) = terms.next() else {
+        return raw_expression("FF(0)");
+    };
+
+    let mut acc = first.clone();
+    let mut power = raw_expression(challenge_name);
+    for term in terms {
+        acc = add_expr(&acc, &mul_expr(term, &power));
+        power = mul_expr(&power, &raw_expression(challenge_name));
+    }
+    acc
+}
+
+/// A subtree's rendered [`PolynomialExpression`] together with its degree, as computed by
+/// [`cap_expression`]. Once returned from that function, `degree` is `<= max_degree` except in
+/// the over-degree cases that function lists (an alias reference of higher degree, or
+/// `max_degree < 2`).
+struct CappedExpression {
+    expr: PolynomialExpression,
+    degree: u64,
+}
+
+/// Caps the degree of `current_expr` at `max_degree`, mirroring [`compute_expression`]'s
+/// bottom-up walk but hoisting over-degree multiplication operands into fresh witness columns
+/// along the way.
+///
+/// A single high-degree custom gate inflates the per-round sumcheck cost for its whole relation
+/// (the prover's work scales with the highest individual subrelation degree), so this is run
+/// whenever [`create_identities`] is given a `max_subrelation_degree`. `alias_degrees` makes an
+/// alias reference count as the alias's own degree rather than 1, so degree is measured as if the
+/// alias were already inlined.
+///
+/// `Add`/`Sub` never raise a node's degree above its operands', so only `Mul` ever needs
+/// extraction. Both operands of a `Mul` node have already been capped by the time it is reached,
+/// but their *product* can still exceed the bound (e.g. two degree-3 operands with
+/// `max_degree = 5`); when that happens, the larger operand is hoisted into a witness column `w`
+/// with a defining relation `w - operand = 0` (of the operand's own degree) and replaced by a
+/// degree-1 reference to `w`, repeating on the other operand if the product still doesn't fit.
+/// This requires `max_degree >= 2`; a smaller bound can't be met by any multiplication and is
+/// left over-degree rather than looping forever.
+///
+/// An alias reference is never split: it counts at the alias's own degree, so an alias whose
+/// degree exceeds `max_degree` stays over-degree, both where it appears and in the defining
+/// relation of any witness column it is hoisted into.
+///
+/// # Panics
+/// Only numbers, references, unary minus and `Add`/`Sub`/`Mul` are walked; a `Pow` node, a
+/// public reference or a challenge panics with `unimplemented!` rather than returning an
+/// [`ExpressionError`].
+#[allow(clippy::too_many_arguments)]
+fn cap_expression<F: FieldElement>(
+    current_expr: &AlgebraicExpression<F>,
+    alias_names: &HashSet<String>,
+    alias_degrees: &HashMap<String, u64>,
+    max_degree: u64,
+    original_id: u64,
+    counter: &mut usize,
+    extra_identities: &mut Vec<BBIdentity>,
+    witness_columns: &mut Vec<String>,
+    shifted_columns: &mut Vec<String>,
+) -> Result<CappedExpression, ExpressionError> {
+    let capped = match current_expr {
+        AlgebraicExpression::Number(_) => CappedExpression {
+            expr: compute_expression(current_expr, alias_names)?,
+            degree: 0,
+        },
+        AlgebraicExpression::Reference(poly) => {
+            let sanitized_name = sanitize_name(&poly.name);
+            let degree = if alias_names.contains(&sanitized_name) {
+                *alias_degrees.get(&sanitized_name).unwrap_or(&1)
+            } else {
+                1
+            };
+            CappedExpression {
+                expr: compute_expression(current_expr, alias_names)?,
+                degree,
+            }
         }
+        AlgebraicExpression::UnaryOperation(AlgebraicUnaryOperation {
+            op: AlgebraicUnaryOperator::Minus,
+            expr: inner,
+        }) => {
+            let capped = cap_expression(
+                inner,
+                alias_names,
+                alias_degrees,
+                max_degree,
+                original_id,
+                counter,
+                extra_identities,
+                witness_columns,
+                shifted_columns,
+            )?;
+            CappedExpression {
+                expr: PolynomialExpression {
+                    pattern_with_placeholders: format!(
+                        "-{}",
+                        capped.expr.pattern_with_placeholders
+                    ),
+                    placeholders: capped.expr.placeholders,
+                },
+                degree: capped.degree,
+            }
+        }
+        AlgebraicExpression::BinaryOperation(AlgebraicBinaryOperation { left, op, right }) => {
+            let mut lhs = cap_expression(
+                left,
+                alias_names,
+                alias_degrees,
+                max_degree,
+                original_id,
+                counter,
+                extra_identities,
+                witness_columns,
+                shifted_columns,
+            )?;
+            let mut rhs = cap_expression(
+                right,
+                alias_names,
+                alias_degrees,
+                max_degree,
+                original_id,
+                counter,
+                extra_identities,
+                witness_columns,
+                shifted_columns,
+            )?;
+
+            match op {
+                AlgebraicBinaryOperator::Add => CappedExpression {
+                    expr: add_expr(&lhs.expr, &rhs.expr),
+                    degree: std::cmp::max(lhs.degree, rhs.degree),
+                },
+                AlgebraicBinaryOperator::Sub => CappedExpression {
+                    expr: sub_expr(&lhs.expr, &rhs.expr),
+                    degree: std::cmp::max(lhs.degree, rhs.degree),
+                },
+                AlgebraicBinaryOperator::Mul => {
+                    while lhs.degree + rhs.degree > max_degree
+                        && (lhs.degree > 1 || rhs.degree > 1)
+                    {
+                        if lhs.degree >= rhs.degree {
+                            lhs = extract_subtree(
+                                lhs,
+                                original_id,
+                                counter,
+                                extra_identities,
+                                witness_columns,
+                                shifted_columns,
+                            );
+                        } else {
+                            rhs = extract_subtree(
+                                rhs,
+                                original_id,
+                                counter,
+                                extra_identities,
+                                witness_columns,
+                                shifted_columns,
+                            );
+                        }
+                    }
+                    CappedExpression {
+                        expr: mul_expr(&lhs.expr, &rhs.expr),
+                        degree: lhs.degree + rhs.degree,
+                    }
+                }
+                _ => unimplemented!("{:?}", op),
+            }
+        }
+        _ => unimplemented!("{:?}", current_expr),
+    };
+    Ok(capped)
+}
+
+/// Hoists `capped` into a fresh witness column `w`, emitting a defining relation `w - capped = 0`
+/// and returning a degree-1 reference to `w` in its place. If `capped` referenced a shifted
+/// column, `w` is registered as needing its own shift, so a later relation can soundly reference
+/// `w`'s `next` value in place of the original subtree's.
+fn extract_subtree(
+    capped: CappedExpression,
+    original_id: u64,
+    counter: &mut usize,
+    extra_identities: &mut Vec<BBIdentity>,
+    witness_columns: &mut Vec<String>,
+    shifted_columns: &mut Vec<String>,
+) -> CappedExpression {
+    *counter += 1;
+    let column = format!("degree_cap_{original_id}_{counter}");
+
+    if contains_shifted_column(&capped.expr) {
+        shifted_columns.push(column.clone());
     }
 
-    IdentitiesOutput {
-        identities,
-        skippable_if: skippable_if_identity,
+    extra_identities.push(BBIdentity {
+        original_id,
+        expression: sub_expr(&column_expression(&column), &capped.expr),
+        label: Some(format!("{column}_degree_cap")),
+        degree: Some(capped.degree),
+    });
+    witness_columns.push(column.clone());
+
+    CappedExpression {
+        expr: column_expression(&column),
+        degree: 1,
     }
 }
 
+/// Whether `expr` references any column's shifted (`next`) value, i.e. a placeholder whose column
+/// name ends in `_shift` (the suffix [`compute_expression`] uses for a shifted reference).
+fn contains_shifted_column(expr: &PolynomialExpression) -> bool {
+    expr.placeholders.values().any(|placeholder| {
+        matches!(placeholder, ExpressionPlaceholder::Column(name) if name.ends_with("_shift"))
+    })
+}
+
 pub fn get_shifted_polys<F: FieldElement>(expressions: Vec<AlgebraicExpression<F>>) -> Vec<String> {
     let mut shifted_polys = HashSet::<String>::new();
     for expr in expressions {